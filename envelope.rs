@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use num_traits::{self, NumCast};
+
+use crate::helpers;
+use crate::point::Point;
+
+/// An axis-aligned bounding box, stored as its minimum and maximum corners.
+#[derive(Debug, Clone)]
+pub struct Envelope([[f64; 2]; 2]);
+
+impl Envelope {
+    /// Construct a new `Envelope` from two opposite corners.
+    ///
+    /// # Examples:
+    ///
+    /// Construct a new `Envelope` from any two opposite corners, as floats or integers.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    ///
+    /// let envelope_1 = Envelope::new([[0., 0.], [2., 4.]]);
+    /// let envelope_2 = Envelope::new([[2, 0], [0, 4]]);
+    ///
+    /// assert_eq!(envelope_1, envelope_2);
+    /// ```
+    pub fn new<T: NumCast>(corners: [[T; 2]; 2]) -> Self {
+        let [[x1, y1], [x2, y2]]: [[f64; 2]; 2] = corners.map(|corner| {
+            corner.map(|coordinate| -> f64 { num_traits::cast(coordinate).unwrap() })
+        });
+        Envelope([[x1.min(x2), y1.min(y2)], [x1.max(x2), y1.max(y2)]])
+    }
+
+    /// Construct a new `Envelope` from its minimum and maximum corners, without reordering them.
+    ///
+    /// Only geographic `Envelope`s that cross the antimeridian should have `min_x > max_x`.
+    pub(crate) fn from_min_max(min: [f64; 2], max: [f64; 2]) -> Self {
+        Envelope([min, max])
+    }
+
+    pub fn min_x(&self) -> f64 {
+        self.0[0][0]
+    }
+
+    pub fn min_y(&self) -> f64 {
+        self.0[0][1]
+    }
+
+    pub fn max_x(&self) -> f64 {
+        self.0[1][0]
+    }
+
+    pub fn max_y(&self) -> f64 {
+        self.0[1][1]
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max_x() - self.min_x()
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max_y() - self.min_y()
+    }
+
+    /// Return the area covered by this `Envelope`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    ///
+    /// let envelope = Envelope::new([[0, 0], [2, 4]]);
+    ///
+    /// assert_eq!(envelope.area(), 8.);
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    /// Return the width of this `Envelope` divided by its height.
+    ///
+    /// A zero-width `Envelope` has an aspect ratio of zero and a zero-height `Envelope` has an
+    /// infinite aspect ratio. If both are zero, the aspect ratio is `NaN`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    ///
+    /// assert_eq!(Envelope::new([[0, 0], [2, 4]]).aspect_ratio(), 0.5);
+    /// assert_eq!(Envelope::new([[1, 0], [1, 4]]).aspect_ratio(), 0.);
+    /// assert_eq!(Envelope::new([[0, 1], [2, 1]]).aspect_ratio(), f64::INFINITY);
+    /// assert!(Envelope::new([[1, 1], [1, 1]]).aspect_ratio().is_nan());
+    /// ```
+    pub fn aspect_ratio(&self) -> f64 {
+        self.width() / self.height()
+    }
+
+    /// Return the smallest `Envelope` that covers all of the given `Envelope`s.
+    ///
+    /// Returns `None` if there are no `Envelope`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    ///
+    /// let envelopes = vec![
+    ///     Envelope::new([[0, 0], [1, 1]]),
+    ///     Envelope::new([[2, -1], [3, 0]]),
+    ///     Envelope::new([[-1, 2], [0, 3]]),
+    /// ];
+    ///
+    /// assert_eq!(Envelope::from_envelopes(envelopes), Some(Envelope::new([[-1, -1], [3, 3]])));
+    /// assert_eq!(Envelope::from_envelopes(Vec::new()), None);
+    /// ```
+    pub fn from_envelopes<I: IntoIterator<Item = Envelope>>(envelopes: I) -> Option<Envelope> {
+        envelopes
+            .into_iter()
+            .reduce(|total, envelope| total.union(&envelope))
+    }
+
+    /// Return `true` if this `Envelope` overlaps or touches another `Envelope`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    ///
+    /// let envelope_1 = Envelope::new([[0, 0], [2, 2]]);
+    /// let envelope_2 = Envelope::new([[1, 1], [3, 3]]);
+    /// let envelope_3 = Envelope::new([[4, 4], [5, 5]]);
+    ///
+    /// assert!(envelope_1.intersects(&envelope_2));
+    /// assert!(!envelope_1.intersects(&envelope_3));
+    /// ```
+    pub fn intersects(&self, other: &Envelope) -> bool {
+        self.min_x() <= other.max_x()
+            && other.min_x() <= self.max_x()
+            && self.min_y() <= other.max_y()
+            && other.min_y() <= self.max_y()
+    }
+
+    /// Return the smallest `Envelope` that covers both this `Envelope` and another `Envelope`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    ///
+    /// let envelope_1 = Envelope::new([[0, 0], [2, 2]]);
+    /// let envelope_2 = Envelope::new([[1, 1], [3, 4]]);
+    ///
+    /// assert_eq!(envelope_1.union(&envelope_2), Envelope::new([[0, 0], [3, 4]]));
+    /// ```
+    pub fn union(&self, other: &Envelope) -> Envelope {
+        Envelope([
+            [
+                self.min_x().min(other.min_x()),
+                self.min_y().min(other.min_y()),
+            ],
+            [
+                self.max_x().max(other.max_x()),
+                self.max_y().max(other.max_y()),
+            ],
+        ])
+    }
+
+    /// Grow this `Envelope` so that it covers `point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let mut envelope = Envelope::new([[0, 0], [1, 1]]);
+    /// envelope.expand_to_include(&Point::new(3, -1));
+    ///
+    /// assert_eq!(envelope, Envelope::new([[0, -1], [3, 1]]));
+    /// ```
+    pub fn expand_to_include(&mut self, point: &Point) {
+        self.0[0][0] = self.min_x().min(point.x());
+        self.0[0][1] = self.min_y().min(point.y());
+        self.0[1][0] = self.max_x().max(point.x());
+        self.0[1][1] = self.max_y().max(point.y());
+    }
+}
+
+/// A geometry that can report its axis-aligned bounding box.
+pub trait Bounded {
+    fn bounds(&self) -> Envelope;
+}
+
+impl Bounded for Envelope {
+    fn bounds(&self) -> Envelope {
+        self.clone()
+    }
+}
+
+/// `Envelope`s are equal if their corners are equal, so they can be used in a `HashSet` or as
+/// `HashMap` keys. As for `Point`s, every `NaN` is equal to every other `NaN`.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use auto_gis_with_rust::envelope::Envelope;
+///
+/// let envelope = Envelope::new([[0, 0], [2, 4]]);
+/// let envelopes: HashSet<Envelope> =
+///     vec![envelope.clone(), envelope, Envelope::new([[2, 0], [0, 4]])].into_iter().collect();
+///
+/// assert_eq!(envelopes.len(), 1);
+/// ```
+impl Eq for Envelope {}
+
+impl Hash for Envelope {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        helpers::hash_coordinate(&self.0[0], state);
+        helpers::hash_coordinate(&self.0[1], state);
+    }
+}
+
+impl PartialEq for Envelope {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Envelope {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(helpers::cmp_canonical(&self.0, &other.0))
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum GeometryError {
+    #[error("too few coordinates, expected 2 or more, found {0})")]
+    TooFewCoords(usize),
+    #[error("too many coordinates, expected {0} or fewer, found {1}")]
+    TooManyCoords(usize, usize),
+    #[error("invalid geometry: {0}")]
+    InvalidGeometry(String),
+    #[error("coordinate {0} can't be converted to a float")]
+    InvalidCoordinate(usize),
+    #[error("couldn't parse WKT: {0}")]
+    ParseError(String),
+}
@@ -0,0 +1,142 @@
+use std::io;
+
+use crate::envelope::Bounded;
+use crate::point::Point;
+
+pub trait Geometry {
+    fn centroid(&self) -> Point;
+
+    /// Compute the geometric center of a geometry, or `None` if it is empty.
+    fn try_centroid(&self) -> Option<Point> {
+        Some(self.centroid())
+    }
+
+    /// Write the WKT representation of a geometry to `writer`, without building a `String`.
+    ///
+    /// Coordinates are written with the `Display` implementation of `f64`, which never uses an
+    /// exponent and prints the fewest digits that read back as the same `f64`. Every coordinate
+    /// round-trips exactly, so there is no precision to cap.
+    fn write_wkt<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Return the WKT representation of a geometry.
+    fn wkt(&self) -> String {
+        let mut buffer = Vec::new();
+        self.write_wkt(&mut buffer)
+            .expect("writing to a Vec can't fail");
+        String::from_utf8(buffer).expect("WKT is always valid UTF-8")
+    }
+
+    /// Return the WKB representation of a geometry, in little-endian byte order if
+    /// `little_endian` is `true` and big-endian otherwise.
+    fn to_wkb(&self, little_endian: bool) -> Vec<u8>;
+
+    /// Return the WKT representation of a geometry, with each part on its own indented line.
+    ///
+    /// This is for reading, not parsing. Geometries with a single part use the compact `wkt`.
+    fn wkt_pretty(&self) -> String {
+        self.wkt()
+    }
+
+    /// Return the centre and radius of a circle covering a geometry.
+    ///
+    /// This is the circle through the corners of the geometry's bounding box, which is quick to
+    /// compute but not the smallest circle covering the geometry. An empty `MultiPoint` has an
+    /// inverted bounding box, so its circle has a `NaN` centre and an infinite radius.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [6, 2], [4, 8]]).unwrap();
+    ///
+    /// assert_eq!(line_string.bounding_circle(), (Point::new(3, 4), 5.));
+    ///
+    /// let (centre, radius) = MultiPoint::new(Vec::<[f64; 2]>::new()).bounding_circle();
+    ///
+    /// assert!(centre.x().is_nan() && centre.y().is_nan());
+    /// assert_eq!(radius, f64::INFINITY);
+    /// ```
+    fn bounding_circle(&self) -> (Point, f64)
+    where
+        Self: Bounded,
+    {
+        let bounds = self.bounds();
+        let centre = Point::new(
+            (bounds.min_x() + bounds.max_x()) / 2f64,
+            (bounds.min_y() + bounds.max_y()) / 2f64,
+        );
+        (centre, bounds.width().hypot(bounds.height()) / 2f64)
+    }
+
+    /// Return a new geometry with `f` applied to every coordinate, keeping its structure.
+    fn map_coords<F: Fn([f64; 2]) -> [f64; 2]>(&self, f: F) -> Self
+    where
+        Self: Sized;
+
+    /// Return a geometry in a canonical form, so that geometries with the same shape compare
+    /// equal however their coordinates are ordered.
+    ///
+    /// The canonical form is:
+    ///
+    /// - consecutive duplicate coordinates are removed;
+    /// - a closed `LineString` runs counter-clockwise and starts at its lexicographically
+    ///   smallest vertex, comparing x and then y;
+    /// - any other `LineString` or `LineSegment` starts at whichever end is lexicographically
+    ///   smaller;
+    /// - the `Point`s of a `MultiPoint` are sorted lexicographically.
+    fn normalize(&self) -> Self
+    where
+        Self: Sized;
+}
+
+/// A geometry that can be simplified, reducing its vertices while keeping its shape to within a
+/// tolerance.
+///
+/// Each implementation keeps the geometry valid for its type, for example a `LineString` always
+/// keeps its endpoints.
+///
+/// # Examples:
+///
+/// ```
+/// use auto_gis_with_rust::geometry::Simplify;
+/// use auto_gis_with_rust::line_string::LineString;
+///
+/// fn simplify_all<T: Simplify>(geometries: &[T], tolerance: f64) -> Vec<T> {
+///     geometries.iter().map(|geometry| geometry.simplify(tolerance)).collect()
+/// }
+///
+/// let line_strings = vec![
+///     LineString::new(vec![[0., 0.], [1., 0.1], [2., 0.]]).unwrap(),
+///     LineString::new(vec![[0., 0.], [1., 1.], [2., 0.]]).unwrap(),
+/// ];
+/// let expected = vec![
+///     LineString::new(vec![[0., 0.], [2., 0.]]).unwrap(),
+///     LineString::new(vec![[0., 0.], [1., 1.], [2., 0.]]).unwrap(),
+/// ];
+///
+/// assert_eq!(simplify_all(&line_strings, 0.5), expected);
+/// ```
+pub trait Simplify {
+    fn simplify(&self, tolerance: f64) -> Self;
+}
+
+/// Approximate equality of coordinates, for comparing geometries after floating-point
+/// transformations.
+pub mod approx {
+    /// Return `true` if the x and y coordinates of `a` and `b` each differ by at most `eps`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::approx::points_approx_eq;
+    ///
+    /// assert!(points_approx_eq(&[0.1 + 0.2, 1.], &[0.3, 1.], 1e-9));
+    /// assert!(!points_approx_eq(&[0.1, 1.], &[0.3, 1.], 1e-9));
+    /// ```
+    pub fn points_approx_eq(a: &[f64; 2], b: &[f64; 2], eps: f64) -> bool {
+        (a[0] - b[0]).abs() <= eps && (a[1] - b[1]).abs() <= eps
+    }
+}
@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+use std::hash::Hasher;
+
+use num_traits::{self, NumCast};
+
+use crate::error::GeometryError;
+
+/// Convert a vector of two-item arrays of generics that implement `NumCast` into a vector of two-item arrays of floats.
+///
+/// Examples:
+///
+/// ```
+/// use auto_gis_with_rust::helpers::get_float_coordinates;
+///
+/// let output = get_float_coordinates(vec![[0, 0], [0, 1], [1, 1]]);
+/// let expected = vec![[0., 0.], [0., 1.], [1., 1.]];
+///
+/// assert_eq!(output, expected)
+/// ```
+pub fn get_float_coordinates<T: NumCast>(coordinates: Vec<[T; 2]>) -> Vec<[f64; 2]> {
+    let float_coordinates: Vec<[f64; 2]> = coordinates
+        .into_iter()
+        .map(|coordinate| {
+            coordinate.map(|coordinate| -> f64 { num_traits::cast(coordinate).unwrap() })
+        })
+        .collect();
+    float_coordinates
+}
+
+/// Convert a vector of two-item arrays of generics that implement `NumCast` into a vector of two-item arrays of floats.
+///
+/// Unlike `get_float_coordinates`, this returns an error for the first coordinate that can't be
+/// converted to a float, instead of panicking.
+///
+/// Examples:
+///
+/// ```
+/// use auto_gis_with_rust::helpers::try_get_float_coordinates;
+/// use num_traits::{NumCast, ToPrimitive};
+///
+/// // A number with no float representation.
+/// struct NotANumber;
+///
+/// impl ToPrimitive for NotANumber {
+///     fn to_i64(&self) -> Option<i64> {
+///         None
+///     }
+///
+///     fn to_u64(&self) -> Option<u64> {
+///         None
+///     }
+/// }
+///
+/// impl NumCast for NotANumber {
+///     fn from<T: ToPrimitive>(_n: T) -> Option<Self> {
+///         None
+///     }
+/// }
+///
+/// let output = try_get_float_coordinates(vec![[0, 0], [0, 1], [1, 1]]).unwrap();
+/// let expected = vec![[0., 0.], [0., 1.], [1., 1.]];
+///
+/// assert_eq!(output, expected);
+/// assert!(try_get_float_coordinates(vec![[NotANumber, NotANumber]]).is_err());
+/// ```
+pub fn try_get_float_coordinates<T: NumCast>(
+    coordinates: Vec<[T; 2]>,
+) -> Result<Vec<[f64; 2]>, GeometryError> {
+    coordinates
+        .into_iter()
+        .enumerate()
+        .map(
+            |(index, [x, y])| match (num_traits::cast(x), num_traits::cast(y)) {
+                (Some(x), Some(y)) => Ok([x, y]),
+                _ => Err(GeometryError::InvalidCoordinate(index)),
+            },
+        )
+        .collect()
+}
+
+/// Return `n` evenly spaced values from `start` to `end`, including both.
+///
+/// Returns an empty vector if `n` is zero and `[start]` if it is one.
+///
+/// Examples:
+///
+/// ```
+/// use auto_gis_with_rust::helpers::linspace;
+///
+/// assert_eq!(linspace(0., 1., 5), vec![0., 0.25, 0.5, 0.75, 1.]);
+/// assert_eq!(linspace(2., 2., 1), vec![2.]);
+/// assert!(linspace(0., 1., 0).is_empty());
+/// ```
+pub fn linspace(start: f64, end: f64, n: usize) -> Vec<f64> {
+    match n {
+        0 => Vec::new(),
+        1 => vec![start],
+        _ => {
+            let last = n - 1;
+            (0..n)
+                .map(|index| {
+                    if index == last {
+                        end
+                    } else {
+                        start + (end - start) * index as f64 / last as f64
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+/// Return the sorted indices of the coordinates kept by Douglas-Peucker simplification.
+///
+/// A coordinate is dropped when it lies within `tolerance` of the segment joining the
+/// coordinates kept either side of it. The first and last coordinates are always kept.
+///
+/// Examples:
+///
+/// ```
+/// use auto_gis_with_rust::helpers::douglas_peucker;
+///
+/// let coordinates = [[0., 0.], [1., 0.1], [2., 0.], [3., 1.]];
+///
+/// assert_eq!(douglas_peucker(&coordinates, 0.5), vec![0, 2, 3]);
+/// ```
+pub fn douglas_peucker(coordinates: &[[f64; 2]], tolerance: f64) -> Vec<usize> {
+    if coordinates.len() < 3 {
+        return (0..coordinates.len()).collect();
+    }
+
+    let last = coordinates.len() - 1;
+    let mut keep = vec![false; coordinates.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    let mut stack: Vec<(usize, usize)> = vec![(0, last)];
+    while let Some((start, end)) = stack.pop() {
+        let farthest = (start + 1..end)
+            .map(|index| {
+                let distance =
+                    distance_to_segment(coordinates[index], coordinates[start], coordinates[end]);
+                (index, distance)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((index, distance)) = farthest {
+            if distance > tolerance {
+                keep[index] = true;
+                stack.push((start, index));
+                stack.push((index, end));
+            }
+        }
+    }
+
+    (0..=last).filter(|&index| keep[index]).collect()
+}
+
+/// Return the z-component of the cross product of `o -> a` and `o -> b`.
+///
+/// Positive if `b` is to the left of `o -> a`, negative if it is to the right and zero if it is collinear.
+///
+/// Examples:
+///
+/// ```
+/// use auto_gis_with_rust::helpers::cross;
+///
+/// assert_eq!(cross([0., 0.], [1., 0.], [0., 1.]), 1.);
+/// assert_eq!(cross([0., 0.], [1., 0.], [0., -1.]), -1.);
+/// assert_eq!(cross([0., 0.], [1., 1.], [2., 2.]), 0.);
+/// ```
+pub fn cross(o: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+/// Return the dot product of two vectors.
+///
+/// Examples:
+///
+/// ```
+/// use auto_gis_with_rust::helpers::dot;
+///
+/// assert_eq!(dot([1., 2.], [3., 4.]), 11.);
+/// assert_eq!(dot([1., 1.], [-1., 1.]), 0.);
+/// ```
+pub fn dot(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+/// The distance from `point` to the closest point on the segment from `a` to `b`.
+pub(crate) fn distance_to_segment(point: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    project_onto_segment(point, a, b).1
+}
+
+/// The fraction along the segment from `a` to `b` of the closest point on it to `point`, and the
+/// distance from `point` to that closest point.
+pub(crate) fn project_onto_segment(point: [f64; 2], a: [f64; 2], b: [f64; 2]) -> (f64, f64) {
+    let dx = b[0] - a[0];
+    let dy = b[1] - a[1];
+    let squared_length = dot([dx, dy], [dx, dy]);
+    let t = if squared_length == 0f64 {
+        0f64
+    } else {
+        (dot([point[0] - a[0], point[1] - a[1]], [dx, dy]) / squared_length).clamp(0f64, 1f64)
+    };
+    let closest_x = a[0] + t * dx;
+    let closest_y = a[1] + t * dy;
+    (t, (point[0] - closest_x).hypot(point[1] - closest_y))
+}
+
+/// Return `value` with every `NaN` replaced by `f64::NAN` and `-0.0` by `0.0`.
+///
+/// The geometry types compare and hash their coordinates in this form, so that every geometry
+/// equals itself and equal geometries hash equally, even with `NaN` coordinates.
+pub(crate) fn canonical(value: f64) -> f64 {
+    if value.is_nan() {
+        f64::NAN
+    } else {
+        value + 0f64
+    }
+}
+
+/// Compare two sequences of coordinates element by element, then by length, with their
+/// coordinates made `canonical`.
+pub(crate) fn cmp_canonical(a: &[[f64; 2]], b: &[[f64; 2]]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| cmp_coordinates(&a.map(canonical), &b.map(canonical)))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+/// Feed the bits of `coordinate` to `state`, so that coordinates which compare equal under
+/// `cmp_canonical` hash equally.
+pub(crate) fn hash_coordinate<H: Hasher>(coordinate: &[f64; 2], state: &mut H) {
+    state.write_u64(canonical(coordinate[0]).to_bits());
+    state.write_u64(canonical(coordinate[1]).to_bits());
+}
+
+/// Compare two coordinates by x, then by y, with `f64::total_cmp`.
+pub(crate) fn cmp_coordinates(a: &[f64; 2], b: &[f64; 2]) -> Ordering {
+    a[0].total_cmp(&b[0]).then_with(|| a[1].total_cmp(&b[1]))
+}
+
+/// Append a WKB header, the byte-order flag and the geometry type code, to `buffer`.
+pub(crate) fn write_wkb_header(buffer: &mut Vec<u8>, little_endian: bool, type_code: u32) {
+    buffer.push(little_endian as u8);
+    write_wkb_u32(buffer, little_endian, type_code);
+}
+
+pub(crate) fn write_wkb_u32(buffer: &mut Vec<u8>, little_endian: bool, value: u32) {
+    if little_endian {
+        buffer.extend_from_slice(&value.to_le_bytes());
+    } else {
+        buffer.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+pub(crate) fn write_wkb_coordinate(
+    buffer: &mut Vec<u8>,
+    little_endian: bool,
+    coordinate: [f64; 2],
+) {
+    for value in coordinate {
+        if little_endian {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        } else {
+            buffer.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+}
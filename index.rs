@@ -0,0 +1,418 @@
+use crate::envelope::{Bounded, Envelope};
+use crate::point::{MultiPoint, Point};
+
+/// The maximum number of children of an `RTree` node.
+const NODE_CAPACITY: usize = 8;
+
+/// A 2-dimensional tree over the `Point`s of a `MultiPoint`, for fast nearest-neighbour queries.
+///
+/// Each entry holds the index of the `Point` in the original `MultiPoint` and its coordinates,
+/// ordered so that the median of every sub-slice is the root of that subtree.
+///
+/// Queries take O(log n) time on average, degrading towards O(n) for badly clustered points.
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct KdTree(Vec<(usize, [f64; 2])>);
+
+impl KdTree {
+    /// Build a new `KdTree` from the `Point`s of a `MultiPoint`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::index::KdTree;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 0], [10, 0], [4, 3]]);
+    /// let kd_tree = KdTree::build(&multi_point);
+    ///
+    /// assert_eq!(kd_tree.nearest(&Point::new(8, 3)), Some((1, 13f64.sqrt())));
+    /// ```
+    pub fn build(multi_point: &MultiPoint) -> Self {
+        let mut entries: Vec<(usize, [f64; 2])> = multi_point
+            .iter()
+            .enumerate()
+            .map(|(index, point)| (index, [point.x(), point.y()]))
+            .collect();
+        sort_subtree(&mut entries, 0);
+        KdTree(entries)
+    }
+
+    /// Return the index and distance of the closest `Point` to `point`.
+    ///
+    /// Returns `None` if the tree is empty.
+    ///
+    /// # Examples:
+    ///
+    /// The result matches a brute-force search with `MultiPoint::nearest`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::index::KdTree;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let mut seed: u64 = 42;
+    /// let mut random = || {
+    ///     seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///     (seed >> 11) as f64 / (1u64 << 53) as f64 * 100.
+    /// };
+    ///
+    /// let coordinates: Vec<[f64; 2]> = (0..500).map(|_| [random(), random()]).collect();
+    /// let multi_point = MultiPoint::new(coordinates);
+    /// let kd_tree = KdTree::build(&multi_point);
+    ///
+    /// for _ in 0..100 {
+    ///     let query = Point::new(random(), random());
+    ///     let (expected_index, _, expected_distance) = multi_point.nearest(&query).unwrap();
+    ///
+    ///     assert_eq!(kd_tree.nearest(&query), Some((expected_index, expected_distance)));
+    /// }
+    ///
+    /// let empty = KdTree::build(&MultiPoint::new(Vec::<[f64; 2]>::new()));
+    ///
+    /// assert_eq!(empty.nearest(&Point::new(0, 0)), None);
+    /// ```
+    pub fn nearest(&self, point: &Point) -> Option<(usize, f64)> {
+        let mut best: Option<(usize, f64)> = None;
+        nearest_in_subtree(&self.0, 0, [point.x(), point.y()], &mut best);
+        best.map(|(index, squared_distance)| (index, squared_distance.sqrt()))
+    }
+
+    /// Return the sorted indices of all `Point`s within `radius` of `point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::index::KdTree;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 0], [1, 0], [3, 0], [0, 2], [5, 5]]);
+    /// let kd_tree = KdTree::build(&multi_point);
+    ///
+    /// assert_eq!(kd_tree.within_radius(&Point::new(0, 0), 2.), vec![0, 1, 3]);
+    /// assert_eq!(kd_tree.within_radius(&Point::new(3., 0.1), 0.5), vec![2]);
+    /// assert!(kd_tree.within_radius(&Point::new(10, 10), 1.).is_empty());
+    /// ```
+    ///
+    /// The result matches a linear scan, including `Point`s exactly `radius` away:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::index::KdTree;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let coordinates: Vec<[i32; 2]> = (0..400).map(|i| [i % 20, i / 20]).collect();
+    /// let multi_point = MultiPoint::new(coordinates);
+    /// let kd_tree = KdTree::build(&multi_point);
+    ///
+    /// for i in 0..50 {
+    ///     let query = Point::new(i * 7 % 23 - 1, i * 11 % 23 - 1);
+    ///     let radius = (i % 6) as f64;
+    ///     let expected: Vec<usize> = multi_point
+    ///         .iter()
+    ///         .enumerate()
+    ///         .filter(|(_, point)| point.squared_distance(&query) <= radius * radius)
+    ///         .map(|(index, _)| index)
+    ///         .collect();
+    ///
+    ///     assert_eq!(kd_tree.within_radius(&query, radius), expected);
+    /// }
+    /// ```
+    pub fn within_radius(&self, point: &Point, radius: f64) -> Vec<usize> {
+        let mut indices: Vec<usize> = Vec::new();
+        within_radius_in_subtree(&self.0, 0, [point.x(), point.y()], radius, &mut indices);
+        indices.sort_unstable();
+        indices
+    }
+}
+
+/// Order `entries` so that the median along `axis` is the root, then recurse into each half.
+fn sort_subtree(entries: &mut [(usize, [f64; 2])], axis: usize) {
+    if entries.len() <= 1 {
+        return;
+    }
+    let median = entries.len() / 2;
+    entries.select_nth_unstable_by(median, |a, b| a.1[axis].total_cmp(&b.1[axis]));
+    let (left, right) = entries.split_at_mut(median);
+    sort_subtree(left, 1 - axis);
+    sort_subtree(&mut right[1..], 1 - axis);
+}
+
+fn squared_distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let dx = b[0] - a[0];
+    let dy = b[1] - a[1];
+    dx * dx + dy * dy
+}
+
+fn nearest_in_subtree(
+    entries: &[(usize, [f64; 2])],
+    axis: usize,
+    target: [f64; 2],
+    best: &mut Option<(usize, f64)>,
+) {
+    if entries.is_empty() {
+        return;
+    }
+    let median = entries.len() / 2;
+    let (index, coordinates) = entries[median];
+
+    let distance = squared_distance(coordinates, target);
+    let is_better = match *best {
+        Some((best_index, best_distance)) => {
+            distance < best_distance || (distance == best_distance && index < best_index)
+        }
+        None => true,
+    };
+    if is_better {
+        *best = Some((index, distance));
+    }
+
+    let difference = target[axis] - coordinates[axis];
+    let (near, far) = if difference < 0f64 {
+        (&entries[..median], &entries[median + 1..])
+    } else {
+        (&entries[median + 1..], &entries[..median])
+    };
+
+    nearest_in_subtree(near, 1 - axis, target, best);
+    let best_distance = best.map_or(f64::INFINITY, |(_, best_distance)| best_distance);
+    if difference * difference <= best_distance {
+        nearest_in_subtree(far, 1 - axis, target, best);
+    }
+}
+
+fn within_radius_in_subtree(
+    entries: &[(usize, [f64; 2])],
+    axis: usize,
+    target: [f64; 2],
+    radius: f64,
+    indices: &mut Vec<usize>,
+) {
+    if entries.is_empty() {
+        return;
+    }
+    let median = entries.len() / 2;
+    let (index, coordinates) = entries[median];
+
+    if squared_distance(coordinates, target) <= radius * radius {
+        indices.push(index);
+    }
+    if target[axis] - radius <= coordinates[axis] {
+        within_radius_in_subtree(&entries[..median], 1 - axis, target, radius, indices);
+    }
+    if target[axis] + radius >= coordinates[axis] {
+        within_radius_in_subtree(&entries[median + 1..], 1 - axis, target, radius, indices);
+    }
+}
+
+/// A static, bounding-box-only spatial index, bulk loaded with the Sort-Tile-Recursive algorithm.
+///
+/// An `RTree` only compares bounding boxes, so it is a pre-filter: the indices it returns are
+/// candidates which still need an exact geometric test.
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct RTree {
+    /// The bounding box of each indexed geometry.
+    bounds: Vec<Envelope>,
+    /// The nodes of each level, from the leaves up to the root, as their bounding box and the
+    /// indices of their children in the level below (or of the geometries, for leaves).
+    levels: Vec<Vec<(Envelope, Vec<usize>)>>,
+}
+
+impl RTree {
+    /// Build a new `RTree` over the bounding boxes of `geometries`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    /// use auto_gis_with_rust::index::RTree;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_strings = vec![
+    ///     LineString::new(vec![[0, 0], [1, 1]]).unwrap(),
+    ///     LineString::new(vec![[5, 5], [6, 5], [6, 6]]).unwrap(),
+    /// ];
+    /// let r_tree = RTree::build(&line_strings);
+    ///
+    /// assert_eq!(r_tree.query(&Envelope::new([[4, 4], [10, 10]])), vec![1]);
+    /// ```
+    pub fn build<T: Bounded>(geometries: &[T]) -> Self {
+        let bounds: Vec<Envelope> = geometries.iter().map(Bounded::bounds).collect();
+
+        let mut levels: Vec<Vec<(Envelope, Vec<usize>)>> = Vec::new();
+        let mut entries: Vec<(Envelope, usize)> = bounds.iter().cloned().zip(0..).collect();
+        while !entries.is_empty() {
+            let level = pack(entries);
+            let is_root = level.len() == 1;
+            entries = if is_root {
+                Vec::new()
+            } else {
+                level
+                    .iter()
+                    .map(|(bounds, _)| bounds.clone())
+                    .zip(0..)
+                    .collect()
+            };
+            levels.push(level);
+        }
+
+        RTree { bounds, levels }
+    }
+
+    /// Return the sorted indices of the geometries whose bounding boxes intersect `area`.
+    ///
+    /// # Examples:
+    ///
+    /// The result matches a brute-force scan over every bounding box. With 300 geometries and at
+    /// most 8 children per node, the tree has three levels.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::{Bounded, Envelope};
+    /// use auto_gis_with_rust::index::RTree;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_strings: Vec<LineString> = (0..300)
+    ///     .map(|i| {
+    ///         let [x, y] = [(i * 37 % 101) as f64, (i * 59 % 97) as f64];
+    ///         LineString::new(vec![[x, y], [x + (i % 7) as f64, y + (i % 5) as f64]]).unwrap()
+    ///     })
+    ///     .collect();
+    /// let r_tree = RTree::build(&line_strings);
+    ///
+    /// for x in (-10..110).step_by(15) {
+    ///     for y in (-10..110).step_by(15) {
+    ///         let area = Envelope::new([[x, y], [x + 12, y + 8]]);
+    ///         let expected: Vec<usize> = line_strings
+    ///             .iter()
+    ///             .enumerate()
+    ///             .filter(|(_, line_string)| line_string.bounds().intersects(&area))
+    ///             .map(|(index, _)| index)
+    ///             .collect();
+    ///
+    ///         assert_eq!(r_tree.query(&area), expected);
+    ///     }
+    /// }
+    /// ```
+    pub fn query(&self, area: &Envelope) -> Vec<usize> {
+        let mut indices: Vec<usize> = Vec::new();
+        if let Some(root_level) = self.levels.len().checked_sub(1) {
+            let mut stack: Vec<(usize, usize)> = vec![(root_level, 0)];
+            while let Some((level, node)) = stack.pop() {
+                let (bounds, children) = &self.levels[level][node];
+                if !bounds.intersects(area) {
+                    continue;
+                }
+                if level == 0 {
+                    indices.extend(
+                        children
+                            .iter()
+                            .filter(|&&index| self.bounds[index].intersects(area)),
+                    );
+                } else {
+                    stack.extend(children.iter().map(|&child| (level - 1, child)));
+                }
+            }
+        }
+        indices.sort_unstable();
+        indices
+    }
+}
+
+/// Return the index pairs of the geometries in `a` and `b` whose bounding boxes intersect.
+///
+/// Like `RTree`, this is a pre-filter: each pair is a candidate which still needs an exact
+/// geometric test. Pairs are sorted by their index in `a`, then by their index in `b`.
+///
+/// # Examples:
+///
+/// The result matches a brute-force comparison of every pair.
+///
+/// ```
+/// use auto_gis_with_rust::envelope::{Bounded, Envelope};
+/// use auto_gis_with_rust::index;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let points: Vec<Point> = (0..20).map(|i| Point::new(i % 5, i / 5)).collect();
+/// let envelopes = vec![
+///     Envelope::new([[0., 0.], [1.5, 1.5]]),
+///     Envelope::new([[2.5, 0.5], [4., 2.]]),
+///     Envelope::new([[10, 10], [11, 11]]),
+/// ];
+///
+/// let mut expected: Vec<(usize, usize)> = Vec::new();
+/// for (i, point) in points.iter().enumerate() {
+///     for (j, envelope) in envelopes.iter().enumerate() {
+///         if point.bounds().intersects(envelope) {
+///             expected.push((i, j));
+///         }
+///     }
+/// }
+///
+/// assert_eq!(index::bbox_join(&points, &envelopes), expected);
+/// assert_eq!(expected.len(), 8);
+/// ```
+///
+/// The same holds when `b` is large enough for a tree several levels deep:
+///
+/// ```
+/// use auto_gis_with_rust::envelope::{Bounded, Envelope};
+/// use auto_gis_with_rust::index;
+/// use auto_gis_with_rust::point::Point;
+///
+/// let points: Vec<Point> = (0..100).map(|i| Point::new(i % 10 * 10, i / 10 * 10)).collect();
+/// let envelopes: Vec<Envelope> = (0..200)
+///     .map(|i| {
+///         let [x, y] = [(i * 37 % 101) as f64, (i * 59 % 97) as f64];
+///         Envelope::new([[x, y], [x + (i % 13) as f64, y + (i % 11) as f64]])
+///     })
+///     .collect();
+///
+/// let mut expected: Vec<(usize, usize)> = Vec::new();
+/// for (i, point) in points.iter().enumerate() {
+///     for (j, envelope) in envelopes.iter().enumerate() {
+///         if point.bounds().intersects(envelope) {
+///             expected.push((i, j));
+///         }
+///     }
+/// }
+///
+/// assert!(!expected.is_empty());
+/// assert_eq!(index::bbox_join(&points, &envelopes), expected);
+/// ```
+pub fn bbox_join<A: Bounded, B: Bounded>(a: &[A], b: &[B]) -> Vec<(usize, usize)> {
+    let r_tree = RTree::build(b);
+    a.iter()
+        .enumerate()
+        .flat_map(|(i, geometry)| {
+            r_tree
+                .query(&geometry.bounds())
+                .into_iter()
+                .map(move |j| (i, j))
+        })
+        .collect()
+}
+
+/// Group `entries` into nodes of at most `NODE_CAPACITY` children, tiling them into vertical
+/// slices by the x-coordinate of their centres and then packing each slice by y-coordinate.
+fn pack(mut entries: Vec<(Envelope, usize)>) -> Vec<(Envelope, Vec<usize>)> {
+    let number_of_nodes = entries.len().div_ceil(NODE_CAPACITY);
+    let number_of_slices = (number_of_nodes as f64).sqrt().ceil() as usize;
+    let slice_size = number_of_slices * NODE_CAPACITY;
+
+    entries.sort_by(|a, b| centre(&a.0)[0].total_cmp(&centre(&b.0)[0]));
+
+    let mut nodes: Vec<(Envelope, Vec<usize>)> = Vec::with_capacity(number_of_nodes);
+    for slice in entries.chunks_mut(slice_size) {
+        slice.sort_by(|a, b| centre(&a.0)[1].total_cmp(&centre(&b.0)[1]));
+        for children in slice.chunks(NODE_CAPACITY) {
+            let bounds = Envelope::from_envelopes(children.iter().map(|(child, _)| child.clone()))
+                .expect("chunks are never empty");
+            nodes.push((bounds, children.iter().map(|(_, index)| *index).collect()));
+        }
+    }
+    nodes
+}
+
+fn centre(envelope: &Envelope) -> [f64; 2] {
+    [
+        (envelope.min_x() + envelope.max_x()) / 2f64,
+        (envelope.min_y() + envelope.max_y()) / 2f64,
+    ]
+}
@@ -1,15 +1,21 @@
 use core::slice::Iter;
+use std::cmp::Ordering;
 use std::convert::From;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::iter::FromIterator;
 use std::vec::IntoIter;
 
+use itertools::Itertools;
 use num_traits::NumCast;
 
+use crate::envelope::{Bounded, Envelope};
 use crate::error::GeometryError;
-use crate::geometry::Geometry;
+use crate::geometry::{approx, Geometry, Simplify};
 use crate::helpers;
-use crate::point::Point;
+use crate::point::{MultiPoint, Point};
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone)]
 pub struct LineSegment([[f64; 2]; 2]);
 
 impl LineSegment {
@@ -41,6 +47,317 @@ impl LineSegment {
     pub fn target(&self) -> Point {
         Point::new(self.0[1][0], self.0[1][1])
     }
+
+    /// Return the compass bearing from the source to the target of this `LineSegment`.
+    ///
+    /// The bearing is in degrees clockwise from north (the positive y-axis), in the range
+    /// `[0, 360)`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// assert_eq!(LineSegment::new([[0, 0], [1, 0]]).azimuth(), 90.);
+    /// assert_eq!(LineSegment::new([[0, 0], [0, -1]]).azimuth(), 180.);
+    /// assert_eq!(LineSegment::new([[0, 0], [-1, 0]]).azimuth(), 270.);
+    /// ```
+    pub fn azimuth(&self) -> f64 {
+        let dx = self.target().x() - self.source().x();
+        let dy = self.target().y() - self.source().y();
+        dx.atan2(dy).to_degrees().rem_euclid(360f64)
+    }
+
+    /// Return the point at fraction `t` along this `LineSegment`, from its source to its target.
+    ///
+    /// `t` is clamped to `[0, 1]`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_segment = LineSegment::new([[0, 0], [2, 4]]);
+    ///
+    /// assert_eq!(line_segment.lerp(0.5), Point::new(1, 2));
+    /// assert_eq!(line_segment.lerp(2.), Point::new(2, 4));
+    /// ```
+    pub fn lerp(&self, t: f64) -> Point {
+        self.source().lerp(&self.target(), t)
+    }
+
+    /// Return a `LineSegment` of `length` crossing this `LineSegment` at right angles.
+    ///
+    /// The tick is centred on the point at fraction `t` along this `LineSegment` and runs from
+    /// its left-hand side to its right-hand side. A zero-length `LineSegment` has no direction,
+    /// so the tick is zero-length too, at its one point.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// let line_segment = LineSegment::new([[0, 0], [2, 0]]);
+    /// let expected = LineSegment::new([[1, 1], [1, -1]]);
+    ///
+    /// assert_eq!(line_segment.perpendicular_at(0.5, 2.), expected);
+    ///
+    /// let zero_length = LineSegment::new([[1, 1], [1, 1]]);
+    ///
+    /// assert_eq!(zero_length.perpendicular_at(0.5, 2.), zero_length);
+    /// ```
+    pub fn perpendicular_at(&self, t: f64, length: f64) -> LineSegment {
+        let [[x1, y1], [x2, y2]] = self.0;
+        let dx = x2 - x1;
+        let dy = y2 - y1;
+        let segment_length = dx.hypot(dy);
+        if segment_length == 0f64 {
+            return self.clone();
+        }
+        let x = x1 + t * dx;
+        let y = y1 + t * dy;
+        let half_dx = -dy / segment_length * length / 2f64;
+        let half_dy = dx / segment_length * length / 2f64;
+        LineSegment::new([[x + half_dx, y + half_dy], [x - half_dx, y - half_dy]])
+    }
+
+    /// Return a copy of this `LineSegment` moved sideways by `distance`.
+    ///
+    /// A positive `distance` moves it to its left-hand side, looking from its source to its
+    /// target, and a negative `distance` to its right. A zero-length `LineSegment` has no sides,
+    /// so it isn't moved.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// let line_segment = LineSegment::new([[0, 0], [1, 0]]);
+    ///
+    /// assert_eq!(line_segment.offset(1.), LineSegment::new([[0, 1], [1, 1]]));
+    /// assert_eq!(line_segment.offset(-2.), LineSegment::new([[0, -2], [1, -2]]));
+    /// ```
+    pub fn offset(&self, distance: f64) -> LineSegment {
+        let [[x1, y1], [x2, y2]] = self.0;
+        let length = distance_between(self.0[0], self.0[1]);
+        if length == 0f64 {
+            return self.clone();
+        }
+        let dx = -(y2 - y1) / length * distance;
+        let dy = (x2 - x1) / length * distance;
+        LineSegment([[x1 + dx, y1 + dy], [x2 + dx, y2 + dy]])
+    }
+
+    /// Project `point` onto this `LineSegment`, returning the fraction along it of the closest
+    /// point to `point` and the distance between them.
+    ///
+    /// The fraction is clamped to `[0, 1]`, so for a `point` beyond either end the closest point
+    /// is that endpoint. For a zero-length `LineSegment` the fraction is zero.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_segment = LineSegment::new([[0, 0], [4, 0]]);
+    ///
+    /// assert_eq!(line_segment.project(&Point::new(2, 3)), (0.5, 3.));
+    /// assert_eq!(line_segment.project(&Point::new(7, 4)), (1., 5.));
+    /// ```
+    pub fn project(&self, point: &Point) -> (f64, f64) {
+        helpers::project_onto_segment(point.coords(), self.0[0], self.0[1])
+    }
+
+    /// Return the closest point on this `LineSegment` to `point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_segment = LineSegment::new([[0, 0], [4, 0]]);
+    ///
+    /// assert_eq!(line_segment.closest_point(&Point::new(1, 3)), Point::new(1, 0));
+    /// assert_eq!(line_segment.closest_point(&Point::new(-2, 1)), Point::new(0, 0));
+    /// ```
+    pub fn closest_point(&self, point: &Point) -> Point {
+        let (t, _) = self.project(point);
+        self.lerp(t)
+    }
+
+    /// Return `true` if `point` lies on this `LineSegment`, within `tolerance`.
+    ///
+    /// Points beyond either end are only on the segment if they are within `tolerance` of
+    /// that end.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_segment = LineSegment::new([[0, 0], [2, 2]]);
+    ///
+    /// assert!(line_segment.contains_point(&Point::new(1, 1), 1e-9));
+    /// assert!(!line_segment.contains_point(&Point::new(3, 3), 1e-9));
+    /// assert!(!line_segment.contains_point(&Point::new(1, 0), 1e-9));
+    /// ```
+    pub fn contains_point(&self, point: &Point, tolerance: f64) -> bool {
+        helpers::distance_to_segment([point.x(), point.y()], self.0[0], self.0[1]) <= tolerance
+    }
+
+    /// Return `true` if this `LineSegment` touches or crosses another `LineSegment`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// let line_segment_1 = LineSegment::new([[0., 0.], [1., 1.]]);
+    /// let line_segment_2 = LineSegment::new([[0., 1.], [1., 0.]]);
+    /// let line_segment_3 = LineSegment::new([[2., 2.], [3., 3.]]);
+    ///
+    /// assert!(line_segment_1.intersects(&line_segment_2));
+    /// assert!(!line_segment_1.intersects(&line_segment_3));
+    /// ```
+    ///
+    /// Segments whose bounding boxes don't overlap are rejected before the orientation tests,
+    /// which gives the same answers as the full computation. Here that is `intersection_parameters`,
+    /// along with a check for an endpoint on the other segment, which covers parallel segments:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let pairs = [
+    ///     // Disjoint bounding boxes.
+    ///     ([[0, 0], [1, 1]], [[2, 2], [3, 3]]),
+    ///     ([[0, 0], [1, 0]], [[0, 2], [1, 3]]),
+    ///     // Bounding boxes that only touch.
+    ///     ([[0, 0], [1, 1]], [[1, 0], [2, -1]]),
+    ///     ([[0, 0], [1, 1]], [[1, 1], [2, 0]]),
+    ///     ([[0, 0], [2, 0]], [[2, 0], [3, 0]]),
+    ///     // Collinear overlaps.
+    ///     ([[0, 0], [2, 2]], [[1, 1], [3, 3]]),
+    ///     ([[0, 0], [3, 0]], [[1, 0], [2, 0]]),
+    ///     // Overlapping bounding boxes without a crossing.
+    ///     ([[0, 0], [2, 2]], [[1, 0], [2, 1]]),
+    ///     ([[0, 0], [4, 4]], [[4, 0], [3, 1]]),
+    ///     // Crossings.
+    ///     ([[0, 0], [2, 2]], [[0, 2], [2, 0]]),
+    ///     ([[0, 0], [4, 0]], [[1, -1], [1, 3]]),
+    ///     ([[0, 0], [2, 2]], [[1, 1], [2, 0]]),
+    /// ];
+    ///
+    /// for (first, second) in pairs {
+    ///     let [first, second] = [LineSegment::new(first), LineSegment::new(second)];
+    ///     let on = |segment: &LineSegment, other: &LineSegment| {
+    ///         [segment.source(), segment.target()]
+    ///             .iter()
+    ///             .any(|point: &Point| other.contains_point(point, 0.))
+    ///     };
+    ///     let expected = first.intersection_parameters(&second).is_some()
+    ///         || on(&first, &second)
+    ///         || on(&second, &first);
+    ///
+    ///     assert_eq!(first.intersects(&second), expected);
+    /// }
+    /// ```
+    pub fn intersects(&self, other: &LineSegment) -> bool {
+        if !self.bounds().intersects(&other.bounds()) {
+            return false;
+        }
+
+        let [a, b] = self.0;
+        let [c, d] = other.0;
+
+        let orientation_1 = helpers::cross(a, b, c);
+        let orientation_2 = helpers::cross(a, b, d);
+        let orientation_3 = helpers::cross(c, d, a);
+        let orientation_4 = helpers::cross(c, d, b);
+
+        if orientation_1 * orientation_2 < 0f64 && orientation_3 * orientation_4 < 0f64 {
+            return true;
+        }
+
+        (orientation_1 == 0f64 && on_bounds(a, b, c))
+            || (orientation_2 == 0f64 && on_bounds(a, b, d))
+            || (orientation_3 == 0f64 && on_bounds(c, d, a))
+            || (orientation_4 == 0f64 && on_bounds(c, d, b))
+    }
+
+    /// Return the fractions `(t, u)` along this `LineSegment` and `other` at which they cross.
+    ///
+    /// The crossing is at `self.lerp(t)` and `other.lerp(u)`, and a fraction of 0 or 1 means it is
+    /// at an endpoint. Returns `None` if the segments don't cross within both of them, or if they
+    /// are parallel, including when they overlap along a shared line.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// let diagonal_1 = LineSegment::new([[0, 0], [2, 2]]);
+    /// let diagonal_2 = LineSegment::new([[0, 2], [2, 0]]);
+    /// let touching = LineSegment::new([[2, 2], [3, 0]]);
+    /// let apart = LineSegment::new([[3, 0], [4, 4]]);
+    ///
+    /// assert_eq!(diagonal_1.intersection_parameters(&diagonal_2), Some((0.5, 0.5)));
+    /// assert_eq!(diagonal_1.intersection_parameters(&touching), Some((1., 0.)));
+    /// assert_eq!(diagonal_1.intersection_parameters(&apart), None);
+    /// ```
+    pub fn intersection_parameters(&self, other: &LineSegment) -> Option<(f64, f64)> {
+        let [a, b] = self.0;
+        let [c, d] = other.0;
+        let r = [b[0] - a[0], b[1] - a[1]];
+        let s = [d[0] - c[0], d[1] - c[1]];
+        let a_to_c = [c[0] - a[0], c[1] - a[1]];
+
+        let denominator = helpers::cross([0f64, 0f64], r, s);
+        if denominator == 0f64 {
+            return None;
+        }
+        let t = helpers::cross([0f64, 0f64], a_to_c, s) / denominator;
+        let u = helpers::cross([0f64, 0f64], a_to_c, r) / denominator;
+        if (0f64..=1f64).contains(&t) && (0f64..=1f64).contains(&u) {
+            Some((t, u))
+        } else {
+            None
+        }
+    }
+
+    /// Return `true` if the endpoints of this `LineSegment` and another `LineSegment` are equal
+    /// within `eps`, in the same order.
+    pub fn approx_eq(&self, other: &LineSegment, eps: f64) -> bool {
+        approx::points_approx_eq(&self.0[0], &other.0[0], eps)
+            && approx::points_approx_eq(&self.0[1], &other.0[1], eps)
+    }
+}
+
+/// The number of equal pieces a segment of `length` must be split into so that none of them are
+/// longer than `max_length`.
+fn number_of_pieces(length: f64, max_length: f64) -> usize {
+    ((length / max_length).ceil() as usize).max(1)
+}
+
+/// The area of the triangle with vertices `a`, `b` and `c`.
+fn triangle_area(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    helpers::cross(a, b, c).abs() / 2f64
+}
+
+fn distance_between(a: [f64; 2], b: [f64; 2]) -> f64 {
+    ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt()
+}
+
+/// Return `true` if `c` lies within the bounding box of `a` and `b`.
+fn on_bounds(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> bool {
+    c[0] >= a[0].min(b[0])
+        && c[0] <= a[0].max(b[0])
+        && c[1] >= a[1].min(b[1])
+        && c[1] <= a[1].max(b[1])
 }
 
 impl Geometry for LineSegment {
@@ -70,7 +387,7 @@ impl Geometry for LineSegment {
         Point::new(x, y)
     }
 
-    /// Return the WKT representation of a geometry.
+    /// Write the WKT representation of a geometry to `writer`.
     ///
     /// ```
     /// use auto_gis_with_rust::geometry::Geometry;
@@ -81,8 +398,9 @@ impl Geometry for LineSegment {
     ///
     /// assert_eq!(line_segment.wkt(), expected_wkt);
     /// ```
-    fn wkt(&self) -> String {
-        format!(
+    fn write_wkt<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(
+            writer,
             "LINESTRING ({} {}, {} {})",
             self.source().x(),
             self.source().y(),
@@ -90,9 +408,111 @@ impl Geometry for LineSegment {
             self.target().y()
         )
     }
+
+    /// Return the WKB representation of a geometry.
+    ///
+    /// A `LineSegment` is written as a two-point WKB LineString.
+    fn to_wkb(&self, little_endian: bool) -> Vec<u8> {
+        LineString::from(self.clone()).to_wkb(little_endian)
+    }
+
+    /// Return a new geometry with `f` applied to every coordinate.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// let line_segment = LineSegment::new([[0, 1], [2, 3]]);
+    /// let expected = LineSegment::new([[0, 2], [4, 6]]);
+    ///
+    /// assert_eq!(line_segment.map_coords(|[x, y]| [x * 2., y * 2.]), expected);
+    /// ```
+    fn map_coords<F: Fn([f64; 2]) -> [f64; 2]>(&self, f: F) -> Self {
+        LineSegment(self.0.map(&f))
+    }
+
+    /// Return a geometry in a canonical form.
+    ///
+    /// For a `LineSegment`, this starts at whichever end is lexicographically smaller.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// let line_segment = LineSegment::new([[1, 0], [0, 1]]);
+    ///
+    /// assert_eq!(line_segment.normalize(), LineSegment::new([[0, 1], [1, 0]]));
+    /// ```
+    fn normalize(&self) -> Self {
+        let [a, b] = self.0;
+        if helpers::cmp_coordinates(&b, &a).is_lt() {
+            LineSegment([b, a])
+        } else {
+            LineSegment([a, b])
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, PartialOrd)]
+impl Bounded for LineSegment {
+    /// Return the bounding box of a geometry.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::{Bounded, Envelope};
+    /// use auto_gis_with_rust::line_string::LineSegment;
+    ///
+    /// let line_segment = LineSegment::new([[0., 1.], [1., 0.]]);
+    ///
+    /// assert_eq!(line_segment.bounds(), Envelope::new([[0., 0.], [1., 1.]]));
+    /// ```
+    fn bounds(&self) -> Envelope {
+        Envelope::new(self.0)
+    }
+}
+
+impl Simplify for LineSegment {
+    /// Simplify a geometry with Douglas-Peucker simplification.
+    ///
+    /// A `LineSegment` has no vertices between its endpoints, so it is returned unchanged.
+    fn simplify(&self, _tolerance: f64) -> Self {
+        LineSegment(self.0)
+    }
+}
+
+/// `LineSegment`s are equal if their coordinates are equal, so they can be used in a `HashSet` or
+/// as `HashMap` keys. As for `Point`s, every `NaN` is equal to every other `NaN`.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use auto_gis_with_rust::line_string::LineSegment;
+///
+/// let line_segment = LineSegment::new([[0, 0], [1, 1]]);
+/// let line_segments: HashSet<LineSegment> = vec![line_segment.clone(), line_segment].into_iter().collect();
+///
+/// assert_eq!(line_segments.len(), 1);
+/// ```
+impl Eq for LineSegment {}
+
+impl Hash for LineSegment {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        helpers::hash_coordinate(&self.0[0], state);
+        helpers::hash_coordinate(&self.0[1], state);
+    }
+}
+
+impl PartialEq for LineSegment {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for LineSegment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(helpers::cmp_canonical(&self.0, &other.0))
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub struct LineSegments(Vec<LineSegment>);
 
 impl IntoIterator for LineSegments {
@@ -117,7 +537,13 @@ impl From<LineString> for LineSegments {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd)]
+impl FromIterator<LineSegment> for LineSegments {
+    fn from_iter<I: IntoIterator<Item = LineSegment>>(iter: I) -> Self {
+        LineSegments(iter.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct LineString(Vec<[f64; 2]>);
 
 impl LineString {
@@ -140,12 +566,1647 @@ impl LineString {
         if number_of_coordinates < 2 {
             Err(GeometryError::TooFewCoords(number_of_coordinates))
         } else {
-            let float_coordinates = helpers::get_float_coordinates(coordinates);
+            let float_coordinates = helpers::try_get_float_coordinates(coordinates)?;
             Ok(LineString(float_coordinates))
         }
     }
 
+    /// Construct a new `LineString` from a flat slice of interleaved x and y coordinates.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::from_flat(&[0., 0., 1., 1.]).unwrap();
+    ///
+    /// assert_eq!(line_string, LineString::new(vec![[0, 0], [1, 1]]).unwrap());
+    /// assert_eq!(line_string.to_flat(), vec![0., 0., 1., 1.]);
+    /// assert!(LineString::from_flat(&[0., 0., 1.]).is_err());
+    /// assert!(LineString::from_flat(&[0., 0.]).is_err());
+    /// ```
+    pub fn from_flat(coordinates: &[f64]) -> Result<Self, GeometryError> {
+        if !coordinates.len().is_multiple_of(2) {
+            return Err(GeometryError::InvalidGeometry(format!(
+                "flat coordinates need an even number of values, found {}",
+                coordinates.len()
+            )));
+        }
+        let number_of_coordinates = coordinates.len() / 2;
+        if number_of_coordinates < 2 {
+            return Err(GeometryError::TooFewCoords(number_of_coordinates));
+        }
+        Ok(LineString(
+            coordinates
+                .chunks_exact(2)
+                .map(|pair| [pair[0], pair[1]])
+                .collect(),
+        ))
+    }
+
+    /// Return the coordinates of this `LineString` as a flat vector of interleaved x and y
+    /// coordinates.
+    pub fn to_flat(&self) -> Vec<f64> {
+        self.iter().flatten().copied().collect()
+    }
+
     pub fn iter(&self) -> Iter<[f64; 2]> {
         self.0.iter()
     }
+
+    /// Iterate over the vertices of this `LineString` as `Point`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 1], [2, 0]]).unwrap();
+    /// let points: Vec<Point> = line_string.points().collect();
+    ///
+    /// assert_eq!(points[0], Point::new(0, 0));
+    /// assert_eq!(points.len(), 3);
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.iter()
+            .map(|coordinate| Point::new(coordinate[0], coordinate[1]))
+    }
+
+    /// Iterate over the vertices of this `LineString` as `Point`s, each with its distance along
+    /// the `LineString` from the first vertex.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 0]]).unwrap();
+    /// let measures: Vec<f64> = line_string.with_measures().map(|(_, measure)| measure).collect();
+    ///
+    /// assert_eq!(measures, vec![0., 1.]);
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [3, 4], [3, 6]]).unwrap();
+    /// let measured: Vec<(Point, f64)> = line_string.with_measures().collect();
+    ///
+    /// assert_eq!(measured[2], (Point::new(3, 6), 7.));
+    /// ```
+    pub fn with_measures(&self) -> impl Iterator<Item = (Point, f64)> + '_ {
+        let mut previous = self.0[0];
+        let mut measure = 0f64;
+        self.iter().map(move |coordinate| {
+            measure += distance_between(previous, *coordinate);
+            previous = *coordinate;
+            (Point::new(coordinate[0], coordinate[1]), measure)
+        })
+    }
+
+    /// Return `true` if this `LineString` and another `LineString` are the same ring.
+    ///
+    /// Both are compared without their closing coordinate, so a ring written with an explicit
+    /// closing coordinate equals one written without it. Unlike `==`, this treats open
+    /// `LineString`s as if they were closed.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let open = LineString::new(vec![[0, 0], [1, 0], [1, 1]]).unwrap();
+    /// let closed = LineString::new(vec![[0, 0], [1, 0], [1, 1], [0, 0]]).unwrap();
+    ///
+    /// assert_ne!(open, closed);
+    /// assert!(open.geom_eq(&closed));
+    /// ```
+    pub fn geom_eq(&self, other: &LineString) -> bool {
+        let without_closing_coordinate = |line_string: &LineString| -> usize {
+            if line_string.is_closed() {
+                line_string.0.len() - 1
+            } else {
+                line_string.0.len()
+            }
+        };
+        self.0[..without_closing_coordinate(self)] == other.0[..without_closing_coordinate(other)]
+    }
+
+    /// Return `true` if this `LineString` is a valid ring.
+    ///
+    /// A ring is closed, has at least four coordinates and doesn't intersect itself.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let square = LineString::new(vec![[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]]).unwrap();
+    /// let bow_tie = LineString::new(vec![[0, 0], [1, 1], [1, 0], [0, 1], [0, 0]]).unwrap();
+    /// let open_square = LineString::new(vec![[0, 0], [1, 0], [1, 1], [0, 1]]).unwrap();
+    ///
+    /// assert!(square.is_ring());
+    /// assert!(!bow_tie.is_ring());
+    /// assert!(!open_square.is_ring());
+    /// ```
+    pub fn is_ring(&self) -> bool {
+        self.0.len() >= 4 && self.is_closed() && !self.validation_report().is_self_intersecting
+    }
+
+    /// Move every vertex within `tolerance` of one of `targets` exactly onto it.
+    ///
+    /// A vertex within `tolerance` of several targets snaps to the nearest one.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [5., 0.], [9.98, 0.01]]).unwrap();
+    /// let stations = MultiPoint::new(vec![[10., 0.], [10.2, 0.]]);
+    /// let expected = LineString::new(vec![[0, 0], [5, 0], [10, 0]]).unwrap();
+    ///
+    /// assert_eq!(line_string.snap_to_points(&stations, 0.1), expected);
+    /// ```
+    pub fn snap_to_points(&self, targets: &MultiPoint, tolerance: f64) -> LineString {
+        LineString(
+            self.points()
+                .map(|point| match targets.nearest(&point) {
+                    Some((_, target, distance)) if distance <= tolerance => target.coords(),
+                    _ => point.coords(),
+                })
+                .collect(),
+        )
+    }
+
+    /// Rotate this `LineString` counter-clockwise by `angle` degrees about `origin`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_string = LineString::new(vec![[1, 0], [2, 0]]).unwrap();
+    /// let rotated = line_string.rotate(90., &Point::new(1, 0));
+    /// let expected = [[1., 0.], [1., 1.]];
+    ///
+    /// for (coordinate, expected) in rotated.iter().zip(expected) {
+    ///     assert!((coordinate[0] - expected[0]).abs() < 1e-12);
+    ///     assert!((coordinate[1] - expected[1]).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn rotate(&self, angle: f64, origin: &Point) -> LineString {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let (origin_x, origin_y) = (origin.x(), origin.y());
+        self.map_coords(|[x, y]| {
+            let dx = x - origin_x;
+            let dy = y - origin_y;
+            [
+                origin_x + dx * cos - dy * sin,
+                origin_y + dx * sin + dy * cos,
+            ]
+        })
+    }
+
+    /// Rotate this `LineString` clockwise by 90 degrees about the origin.
+    ///
+    /// Like the other right-angle transforms, this swaps and negates coordinates, so it is exact.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 1], [2, 1]]).unwrap();
+    ///
+    /// assert_eq!(line_string.rotate_90_cw(), LineString::new(vec![[1, 0], [1, -2]]).unwrap());
+    /// ```
+    pub fn rotate_90_cw(&self) -> LineString {
+        self.map_coords(|[x, y]| [y, -x])
+    }
+
+    /// Rotate this `LineString` counter-clockwise by 90 degrees about the origin.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[1, 0], [1, 2]]).unwrap();
+    ///
+    /// assert_eq!(line_string.rotate_90_ccw(), LineString::new(vec![[0, 1], [-2, 1]]).unwrap());
+    /// ```
+    pub fn rotate_90_ccw(&self) -> LineString {
+        self.map_coords(|[x, y]| [-y, x])
+    }
+
+    /// Negate the x coordinates of this `LineString`, mirroring it across the y-axis.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[1, 0], [2, 3]]).unwrap();
+    ///
+    /// assert_eq!(line_string.mirror_x(), LineString::new(vec![[-1, 0], [-2, 3]]).unwrap());
+    /// ```
+    pub fn mirror_x(&self) -> LineString {
+        self.map_coords(|[x, y]| [-x, y])
+    }
+
+    /// Negate the y coordinates of this `LineString`, mirroring it across the x-axis.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[1, 0], [2, 3]]).unwrap();
+    ///
+    /// assert_eq!(line_string.mirror_y(), LineString::new(vec![[1, 0], [2, -3]]).unwrap());
+    /// ```
+    pub fn mirror_y(&self) -> LineString {
+        self.map_coords(|[x, y]| [x, -y])
+    }
+
+    /// Swap the x and y coordinates of every vertex of this `LineString`.
+    ///
+    /// This fixes coordinates given in latitude, longitude order, as many geographic coordinate
+    /// systems such as EPSG:4326 officially define them, when longitude, latitude (x, y) order
+    /// was expected, as in GeoJSON and WKT.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[1, 2], [3, 4]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.flip_coordinates(),
+    ///     LineString::new(vec![[2, 1], [4, 3]]).unwrap()
+    /// );
+    /// ```
+    pub fn flip_coordinates(&self) -> LineString {
+        self.map_coords(|[x, y]| [y, x])
+    }
+
+    /// Return a new `LineString` with `f` applied to every coordinate, removing any consecutive
+    /// duplicates `f` creates.
+    ///
+    /// Duplicates are merged into one vertex. If fewer than two distinct coordinates are left,
+    /// this returns `GeometryError::TooFewCoords` rather than a zero-length `LineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let snap = |[x, y]: [f64; 2]| [x.round(), y.round()];
+    /// let line_string = LineString::new(vec![[0., 0.], [0.9, 1.1], [1.2, 0.8], [3., 1.]]).unwrap();
+    /// let short = LineString::new(vec![[0., 0.], [0.2, 0.1]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.map_coords_validated(snap).unwrap(),
+    ///     LineString::new(vec![[0, 0], [1, 1], [3, 1]]).unwrap()
+    /// );
+    /// assert!(short.map_coords_validated(snap).is_err());
+    /// ```
+    pub fn map_coords_validated<F: Fn([f64; 2]) -> [f64; 2]>(
+        &self,
+        f: F,
+    ) -> Result<LineString, GeometryError> {
+        let mut coordinates: Vec<[f64; 2]> = self.iter().map(|coordinate| f(*coordinate)).collect();
+        coordinates.dedup();
+        if coordinates.len() < 2 {
+            Err(GeometryError::TooFewCoords(coordinates.len()))
+        } else {
+            Ok(LineString(coordinates))
+        }
+    }
+
+    /// Rotate this `LineString` about its start so that its end lies on `target_bearing`.
+    ///
+    /// Bearings are in degrees clockwise from north, as returned by `LineSegment::azimuth`. A
+    /// closed `LineString` has no overall direction and is treated as pointing north.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 1], [2, 2]]).unwrap();
+    /// let aligned = line_string.align_to(90.);
+    ///
+    /// assert!(aligned.iter().all(|coordinate| coordinate[1].abs() < 1e-12));
+    /// assert!((aligned.iter().last().unwrap()[0] - 8f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn align_to(&self, target_bearing: f64) -> LineString {
+        let start = self.points().next().expect("a LineString has coordinates");
+        let end = self.points().last().expect("a LineString has coordinates");
+        let bearing = LineSegment::new([[start.x(), start.y()], [end.x(), end.y()]]).azimuth();
+        self.rotate(bearing - target_bearing, &start)
+    }
+
+    /// Return the total length of the segments of this `LineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [3, 4], [3, 0]]).unwrap();
+    ///
+    /// assert_eq!(line_string.length(), 9.);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.0
+            .windows(2)
+            .map(|pair| distance_between(pair[0], pair[1]))
+            .sum()
+    }
+
+    /// Return the `Point` at `distance` along this `LineString` and a value interpolated for it.
+    ///
+    /// `vertex_values` holds one value per vertex. The value for the returned `Point` is
+    /// `interpolate(start_value, end_value, fraction)`, where the values are those of the
+    /// vertices either side of the `Point` and `fraction` is how far it is between them.
+    /// `distance` is clamped to the length of the `LineString`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't exactly one value per vertex.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [10, 0], [10, 10]]).unwrap();
+    /// let temperatures = [10., 20., 40.];
+    /// let (point, temperature) =
+    ///     line_string.interpolate_with(15., &temperatures, |start, end, fraction| {
+    ///         start + (end - start) * fraction
+    ///     });
+    ///
+    /// assert_eq!(point, Point::new(10, 5));
+    /// assert_eq!(temperature, 30.);
+    /// ```
+    pub fn interpolate_with<T, F>(
+        &self,
+        distance: f64,
+        vertex_values: &[T],
+        interpolate: F,
+    ) -> (Point, T)
+    where
+        F: Fn(&T, &T, f64) -> T,
+    {
+        assert_eq!(
+            vertex_values.len(),
+            self.0.len(),
+            "expected one value per vertex"
+        );
+
+        let last_segment = self.0.len() - 2;
+        let mut remaining = distance.max(0f64);
+        for (index, pair) in self.0.windows(2).enumerate() {
+            let segment_length = distance_between(pair[0], pair[1]);
+            if remaining <= segment_length || index == last_segment {
+                let fraction = if segment_length == 0f64 {
+                    0f64
+                } else {
+                    (remaining / segment_length).min(1f64)
+                };
+                let point = Point::new(
+                    pair[0][0] + (pair[1][0] - pair[0][0]) * fraction,
+                    pair[0][1] + (pair[1][1] - pair[0][1]) * fraction,
+                );
+                let value = interpolate(&vertex_values[index], &vertex_values[index + 1], fraction);
+                return (point, value);
+            }
+            remaining -= segment_length;
+        }
+        unreachable!("a LineString has at least one segment")
+    }
+
+    /// Return the first segment of this `LineString`, from its first to its second coordinate.
+    ///
+    /// The rest of the `LineString` is ignored, so this is only the inverse of
+    /// `LineString::from(line_segment)` for two-coordinate `LineString`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineSegment, LineString};
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 1], [2, 0]]).unwrap();
+    ///
+    /// assert_eq!(line_string.first_segment(), Some(LineSegment::new([[0, 0], [1, 1]])));
+    /// ```
+    pub fn first_segment(&self) -> Option<LineSegment> {
+        match self.0.as_slice() {
+            [first, second, ..] => Some(LineSegment::new([*first, *second])),
+            _ => None,
+        }
+    }
+
+    /// Return the `Point` at `distance` along this `LineString`.
+    ///
+    /// `distance` is clamped to the length of the `LineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [10, 0], [10, 10]]).unwrap();
+    ///
+    /// assert_eq!(line_string.interpolate_distance(15.), Point::new(10, 5));
+    /// assert_eq!(line_string.interpolate_distance(25.), Point::new(10, 10));
+    /// ```
+    pub fn interpolate_distance(&self, distance: f64) -> Point {
+        let vertex_values = vec![(); self.0.len()];
+        let (point, _) = self.interpolate_with(distance, &vertex_values, |_, _, _| ());
+        point
+    }
+
+    /// Split this `LineString` at each of `distances` along it.
+    ///
+    /// `distances` should be sorted in increasing order. Distances at or before the start, at
+    /// or beyond the end, or not greater than the previous distance are ignored, so each piece
+    /// has a positive length.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [5, 0], [10, 0]]).unwrap();
+    /// let pieces = line_string.cut_at_distances(&[3., 7., 12.]);
+    /// let lengths: Vec<f64> = pieces.iter().map(LineString::length).collect();
+    ///
+    /// assert_eq!(lengths, vec![3., 4., 3.]);
+    /// assert_eq!(pieces[1], LineString::new(vec![[3, 0], [5, 0], [7, 0]]).unwrap());
+    /// ```
+    pub fn cut_at_distances(&self, distances: &[f64]) -> Vec<LineString> {
+        let length = self.length();
+        let mut cuts: Vec<f64> = vec![0f64];
+        for &distance in distances {
+            if distance > cuts[cuts.len() - 1] && distance < length {
+                cuts.push(distance);
+            }
+        }
+        cuts.push(length);
+
+        cuts.windows(2)
+            .map(|pair| self.substring(pair[0], pair[1]))
+            .collect()
+    }
+
+    /// Return the part of this `LineString` between two distances along it.
+    ///
+    /// The new endpoints are interpolated along the segments they fall on, and the vertices
+    /// between them are kept. Returns an error unless
+    /// `0 <= start_distance < end_distance <= length`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 0]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.sub_line(0.25, 0.75).unwrap(),
+    ///     LineString::new(vec![[0.25, 0.], [0.75, 0.]]).unwrap()
+    /// );
+    /// assert!(line_string.sub_line(0.75, 0.25).is_err());
+    /// assert!(line_string.sub_line(0.5, 2.).is_err());
+    /// ```
+    pub fn sub_line(
+        &self,
+        start_distance: f64,
+        end_distance: f64,
+    ) -> Result<LineString, GeometryError> {
+        let length = self.length();
+        if !(0f64 <= start_distance && start_distance < end_distance && end_distance <= length) {
+            return Err(GeometryError::InvalidGeometry(format!(
+                "sub_line needs 0 <= start < end <= {}, found start {} and end {}",
+                length, start_distance, end_distance
+            )));
+        }
+        Ok(self.substring(start_distance, end_distance))
+    }
+
+    /// Return the part of this `LineString` between two distances along it, where
+    /// `0 <= start_distance <= end_distance <= length`.
+    fn substring(&self, start_distance: f64, end_distance: f64) -> LineString {
+        let mut coordinates: Vec<[f64; 2]> =
+            vec![self.interpolate_distance(start_distance).coords()];
+        let mut distance = 0f64;
+        for pair in self.0.windows(2) {
+            distance += distance_between(pair[0], pair[1]);
+            if distance > start_distance && distance < end_distance {
+                coordinates.push(pair[1]);
+            }
+        }
+        coordinates.push(self.interpolate_distance(end_distance).coords());
+        LineString(coordinates)
+    }
+
+    /// Return `true` if the first and last coordinates of this `LineString` are equal.
+    pub fn is_closed(&self) -> bool {
+        self.0.first() == self.0.last()
+    }
+
+    /// Join this `LineString` and another `LineString` that share an endpoint.
+    ///
+    /// Endpoints are shared if they are within `tolerance` of each other. `other` is reversed if
+    /// needed so that the result runs continuously, and the shared vertex is only included once.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string_1 = LineString::new(vec![[0, 0], [1, 0]]).unwrap();
+    /// let line_string_2 = LineString::new(vec![[2, 0], [1, 0]]).unwrap();
+    /// let expected = LineString::new(vec![[0, 0], [1, 0], [2, 0]]).unwrap();
+    ///
+    /// assert_eq!(line_string_1.merge(&line_string_2, 0.).unwrap(), expected);
+    /// ```
+    ///
+    /// `LineString`s that don't share an endpoint can't be merged.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string_1 = LineString::new(vec![[0, 0], [1, 0]]).unwrap();
+    /// let line_string_2 = LineString::new(vec![[2, 0], [3, 0]]).unwrap();
+    ///
+    /// assert!(line_string_1.merge(&line_string_2, 0.5).is_err());
+    /// ```
+    pub fn merge(&self, other: &LineString, tolerance: f64) -> Result<LineString, GeometryError> {
+        let is_shared =
+            |a: &[f64; 2], b: &[f64; 2]| -> bool { distance_between(*a, *b) <= tolerance };
+        let self_start = &self.0[0];
+        let self_end = &self.0[self.0.len() - 1];
+        let other_start = &other.0[0];
+        let other_end = &other.0[other.0.len() - 1];
+
+        let coordinates: Vec<[f64; 2]> = if is_shared(self_end, other_start) {
+            self.iter().chain(other.iter().skip(1)).copied().collect()
+        } else if is_shared(self_end, other_end) {
+            self.iter()
+                .chain(other.iter().rev().skip(1))
+                .copied()
+                .collect()
+        } else if is_shared(self_start, other_end) {
+            other.iter().chain(self.iter().skip(1)).copied().collect()
+        } else if is_shared(self_start, other_start) {
+            other
+                .iter()
+                .rev()
+                .chain(self.iter().skip(1))
+                .copied()
+                .collect()
+        } else {
+            return Err(GeometryError::InvalidGeometry(String::from(
+                "line strings do not share an endpoint",
+            )));
+        };
+
+        Ok(LineString(coordinates))
+    }
+
+    /// Return a copy of this `LineString` without consecutive duplicate coordinates.
+    ///
+    /// If every coordinate is the same, the first and last are kept so the result is still a
+    /// valid `LineString`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [0, 0], [1, 1], [1, 1], [0, 0]]).unwrap();
+    /// let expected = LineString::new(vec![[0, 0], [1, 1], [0, 0]]).unwrap();
+    ///
+    /// assert_eq!(line_string.dedup(), expected);
+    /// ```
+    pub fn dedup(&self) -> LineString {
+        let mut coordinates = self.0.clone();
+        coordinates.dedup();
+        if coordinates.len() < 2 {
+            coordinates.push(coordinates[0]);
+        }
+        LineString(coordinates)
+    }
+
+    /// Return a copy of this `LineString` without vertices that lie on a straight line between
+    /// their neighbours.
+    ///
+    /// A vertex is dropped if it, and every vertex dropped since the last kept one, is within
+    /// `tolerance` of the segment from the last kept vertex to the vertex after it, so no vertex
+    /// ends up further than `tolerance` from the result. The endpoints are always kept. Unlike
+    /// `simplify`, a dropped vertex must lie between its neighbours, so spikes are kept.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{LineSegment, LineSegments, LineString};
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let straight = LineString::new(vec![[0, 0], [1, 1], [2, 2]]).unwrap();
+    /// let corner = LineString::new(vec![[0, 0], [1, 0], [2, 0], [2, 1]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     straight.remove_collinear(1e-9),
+    ///     LineString::new(vec![[0, 0], [2, 2]]).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     corner.remove_collinear(1e-9),
+    ///     LineString::new(vec![[0, 0], [2, 0], [2, 1]]).unwrap()
+    /// );
+    ///
+    /// // A gentle arc bends by less than `tolerance` at each vertex, but not overall.
+    /// let arc = LineString::new(
+    ///     (0..=200)
+    ///         .map(|i| {
+    ///             let angle = i as f64 / 200. * std::f64::consts::FRAC_PI_2;
+    ///             [10. * angle.cos(), 10. * angle.sin()]
+    ///         })
+    ///         .collect(),
+    /// )
+    /// .unwrap();
+    /// let segments: Vec<LineSegment> = LineSegments::from(arc.remove_collinear(0.01))
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// for &[x, y] in arc.iter() {
+    ///     let deviation = segments
+    ///         .iter()
+    ///         .map(|segment| segment.project(&Point::new(x, y)).1)
+    ///         .fold(f64::INFINITY, f64::min);
+    ///     assert!(deviation <= 0.01);
+    /// }
+    /// ```
+    pub fn remove_collinear(&self, tolerance: f64) -> LineString {
+        let last = self.0.len() - 1;
+        let mut coordinates: Vec<[f64; 2]> = vec![self.0[0]];
+        let mut kept = 0;
+        for index in 1..last {
+            let previous = self.0[kept];
+            let next = self.0[index + 1];
+            if (kept + 1..=index).any(|dropped| {
+                helpers::distance_to_segment(self.0[dropped], previous, next) > tolerance
+            }) {
+                coordinates.push(self.0[index]);
+                kept = index;
+            }
+        }
+        coordinates.push(self.0[last]);
+        LineString(coordinates)
+    }
+
+    /// Clean this `LineString` and report how many vertices each step removed.
+    ///
+    /// The steps are, in order:
+    ///
+    /// 1. `dedup`, removing consecutive duplicate vertices, which are what make zero-length
+    ///    segments.
+    /// 2. `simplify`, with `tolerance`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::{CleanStats, LineString};
+    ///
+    /// let line_string = LineString::new(vec![
+    ///     [0., 0.], [0., 0.], [1., 0.001], [1., 0.], [2., 0.01], [3., 0.], [3., 0.], [3., 3.],
+    /// ])
+    /// .unwrap();
+    /// let (cleaned, stats) = line_string.clean(0.1);
+    ///
+    /// assert_eq!(cleaned, LineString::new(vec![[0, 0], [3, 0], [3, 3]]).unwrap());
+    /// assert_eq!(
+    ///     stats,
+    ///     CleanStats {
+    ///         duplicates_removed: 2,
+    ///         simplified_removed: 3,
+    ///     }
+    /// );
+    /// ```
+    pub fn clean(&self, tolerance: f64) -> (LineString, CleanStats) {
+        let deduped = self.dedup();
+        let simplified = deduped.simplify(tolerance);
+
+        let stats = CleanStats {
+            duplicates_removed: self.0.len() - deduped.0.len(),
+            simplified_removed: deduped.0.len() - simplified.0.len(),
+        };
+        (simplified, stats)
+    }
+
+    /// Insert evenly spaced vertices so that no segment is longer than `max_segment_length`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_segment_length` isn't positive. Use `densify_capped` for untrusted input.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [3, 0], [3, 1]]).unwrap();
+    /// let expected = LineString::new(vec![[0, 0], [1, 0], [2, 0], [3, 0], [3, 1]]).unwrap();
+    ///
+    /// assert_eq!(line_string.densify(1.), expected);
+    /// ```
+    pub fn densify(&self, max_segment_length: f64) -> LineString {
+        assert!(
+            max_segment_length > 0f64,
+            "max_segment_length must be positive"
+        );
+        self.densify_unchecked(max_segment_length)
+    }
+
+    /// Densify this `LineString` like `densify`, unless that would need more than
+    /// `max_total_vertices` vertices.
+    ///
+    /// The number of vertices is worked out before any are added, so a tiny
+    /// `max_segment_length` returns an error rather than exhausting memory.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::error::GeometryError;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1000, 0]]).unwrap();
+    ///
+    /// assert_eq!(line_string.densify_capped(100., 11).unwrap().iter().len(), 11);
+    /// assert!(matches!(
+    ///     line_string.densify_capped(1e-9, 1000),
+    ///     Err(GeometryError::TooManyCoords(1000, _))
+    /// ));
+    /// ```
+    pub fn densify_capped(
+        &self,
+        max_segment_length: f64,
+        max_total_vertices: usize,
+    ) -> Result<LineString, GeometryError> {
+        let number_of_vertices = self
+            .0
+            .windows(2)
+            .map(|pair| number_of_pieces(distance_between(pair[0], pair[1]), max_segment_length))
+            .fold(1usize, usize::saturating_add);
+        if number_of_vertices > max_total_vertices {
+            Err(GeometryError::TooManyCoords(
+                max_total_vertices,
+                number_of_vertices,
+            ))
+        } else {
+            Ok(self.densify_unchecked(max_segment_length))
+        }
+    }
+
+    fn densify_unchecked(&self, max_segment_length: f64) -> LineString {
+        let mut coordinates: Vec<[f64; 2]> = vec![self.0[0]];
+        for pair in self.0.windows(2) {
+            let [[x1, y1], [x2, y2]] = [pair[0], pair[1]];
+            let pieces = number_of_pieces(distance_between(pair[0], pair[1]), max_segment_length);
+            let fractions = helpers::linspace(0f64, 1f64, pieces + 1);
+            for fraction in &fractions[1..pieces] {
+                coordinates.push([x1 + (x2 - x1) * fraction, y1 + (y2 - y1) * fraction]);
+            }
+            coordinates.push(pair[1]);
+        }
+        LineString(coordinates)
+    }
+
+    /// Return a copy of this `LineString` with its sharper corners rounded into arcs.
+    ///
+    /// Wherever consecutive segments turn by more than `max_turn_degrees`, the corner vertex is
+    /// replaced by a circular arc tangent to both segments. The arc starts and ends a third of
+    /// the length of the shorter segment from the corner, and is split into enough equal pieces
+    /// that no two consecutive pieces turn by more than `max_turn_degrees`. Gentler corners,
+    /// straight runs and the endpoints are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_turn_degrees` isn't positive and finite.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let l_shape = LineString::new(vec![[0, 0], [6, 0], [6, 6]]).unwrap();
+    /// let rounded = l_shape.densify_by_angle(30.);
+    /// let coordinates: Vec<[f64; 2]> = rounded.iter().copied().collect();
+    ///
+    /// assert_eq!(coordinates.len(), 6);
+    /// assert_eq!(coordinates[..2], [[0., 0.], [4., 0.]]);
+    /// assert_eq!(coordinates[4..], [[6., 2.], [6., 6.]]);
+    /// for coordinate in &coordinates[1..5] {
+    ///     let radius = (coordinate[0] - 4.).hypot(coordinate[1] - 2.);
+    ///     assert!((radius - 2.).abs() < 1e-12);
+    /// }
+    ///
+    /// let straight = LineString::new(vec![[0, 0], [1, 0], [2, 0]]).unwrap();
+    ///
+    /// assert_eq!(straight.densify_by_angle(30.), straight);
+    /// ```
+    ///
+    /// A zero `max_turn_degrees` would need infinitely many pieces, so it is rejected:
+    ///
+    /// ```should_panic
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let l_shape = LineString::new(vec![[0, 0], [6, 0], [6, 6]]).unwrap();
+    /// l_shape.densify_by_angle(0.);
+    /// ```
+    pub fn densify_by_angle(&self, max_turn_degrees: f64) -> LineString {
+        assert!(
+            max_turn_degrees > 0f64 && max_turn_degrees.is_finite(),
+            "max_turn_degrees must be positive and finite"
+        );
+        let max_turn = max_turn_degrees.to_radians();
+        let mut coordinates: Vec<[f64; 2]> = vec![self.0[0]];
+        for triple in self.0.windows(3) {
+            let [a, b, c] = [triple[0], triple[1], triple[2]];
+            let length_in = distance_between(a, b);
+            let length_out = distance_between(b, c);
+            let direction_in = [(b[0] - a[0]) / length_in, (b[1] - a[1]) / length_in];
+            let direction_out = [(c[0] - b[0]) / length_out, (c[1] - b[1]) / length_out];
+            let turn = helpers::cross([0f64, 0f64], direction_in, direction_out)
+                .atan2(helpers::dot(direction_in, direction_out));
+            if length_in == 0f64 || length_out == 0f64 || turn.abs() <= max_turn {
+                coordinates.push(b);
+                continue;
+            }
+
+            let tangent_length = length_in.min(length_out) / 3f64;
+            let radius = tangent_length / (turn.abs() / 2f64).tan();
+            let start = [
+                b[0] - direction_in[0] * tangent_length,
+                b[1] - direction_in[1] * tangent_length,
+            ];
+            // The centre is to the left of the incoming segment for a left turn, and to the
+            // right for a right turn.
+            let side = turn.signum();
+            let centre = [
+                start[0] - direction_in[1] * radius * side,
+                start[1] + direction_in[0] * radius * side,
+            ];
+            let pieces = (turn.abs() / max_turn).ceil() as usize;
+            for piece in 0..pieces {
+                let angle = turn * piece as f64 / pieces as f64;
+                let (sin, cos) = angle.sin_cos();
+                let dx = start[0] - centre[0];
+                let dy = start[1] - centre[1];
+                coordinates.push([
+                    centre[0] + dx * cos - dy * sin,
+                    centre[1] + dx * sin + dy * cos,
+                ]);
+            }
+            coordinates.push([
+                b[0] + direction_out[0] * tangent_length,
+                b[1] + direction_out[1] * tangent_length,
+            ]);
+        }
+        coordinates.push(self.0[self.0.len() - 1]);
+        LineString(coordinates)
+    }
+
+    /// Smooth this `LineString` with `iterations` rounds of Chaikin's corner cutting.
+    ///
+    /// Each round replaces every segment by the points a quarter and three quarters of the way
+    /// along it, cutting off every corner. The endpoints are kept, so a `LineString` of `n`
+    /// vertices has `2n` after one round and `2^k * n` after `k`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let corner = LineString::new(vec![[0, 0], [4, 0], [4, 4]]).unwrap();
+    /// let expected = LineString::new(vec![[0, 0], [1, 0], [3, 0], [4, 1], [4, 3], [4, 4]]).unwrap();
+    ///
+    /// assert_eq!(corner.chaikin(1), expected);
+    /// assert_eq!(corner.chaikin(3).iter().count(), 24);
+    /// assert_eq!(corner.chaikin(0), corner);
+    /// ```
+    pub fn chaikin(&self, iterations: usize) -> LineString {
+        let mut coordinates = self.0.clone();
+        for _ in 0..iterations {
+            let last = coordinates[coordinates.len() - 1];
+            let mut smoothed: Vec<[f64; 2]> = Vec::with_capacity(coordinates.len() * 2);
+            smoothed.push(coordinates[0]);
+            for pair in coordinates.windows(2) {
+                let [[x1, y1], [x2, y2]] = [pair[0], pair[1]];
+                smoothed.push([0.75 * x1 + 0.25 * x2, 0.75 * y1 + 0.25 * y2]);
+                smoothed.push([0.25 * x1 + 0.75 * x2, 0.25 * y1 + 0.75 * y2]);
+            }
+            smoothed.push(last);
+            coordinates = smoothed;
+        }
+        LineString(coordinates)
+    }
+
+    /// Return the sorted indices of the vertices kept by Douglas-Peucker simplification.
+    ///
+    /// A vertex is dropped when it lies within `tolerance` of the segment joining the vertices
+    /// kept either side of it. The first and last vertices are always kept, so the indices can be
+    /// used to carry per-vertex attributes across to the simplified geometry.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [1., 0.1], [2., 0.]]).unwrap();
+    ///
+    /// assert_eq!(line_string.simplify_indices(0.5), vec![0, 2]);
+    /// assert_eq!(line_string.simplify_indices(0.05), vec![0, 1, 2]);
+    /// ```
+    pub fn simplify_indices(&self, tolerance: f64) -> Vec<usize> {
+        helpers::douglas_peucker(&self.0, tolerance)
+    }
+
+    /// Simplify this `LineString` into `out`, reusing its allocation.
+    ///
+    /// `out` is cleared and then filled with the coordinates `simplify` would keep. Its capacity
+    /// is kept between calls, so passing the same buffer for many `LineString`s avoids
+    /// allocating a new coordinate `Vec` for each of them.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Simplify;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_strings = vec![
+    ///     LineString::new(vec![[0., 0.], [1., 0.1], [2., 0.], [3., 1.]]).unwrap(),
+    ///     LineString::new(vec![[0., 0.], [1., 1.]]).unwrap(),
+    /// ];
+    /// let mut buffer = Vec::new();
+    ///
+    /// for line_string in &line_strings {
+    ///     line_string.simplify_into(0.5, &mut buffer);
+    ///     let simplified = line_string.simplify(0.5);
+    ///
+    ///     assert_eq!(buffer, simplified.iter().copied().collect::<Vec<[f64; 2]>>());
+    /// }
+    /// ```
+    pub fn simplify_into(&self, tolerance: f64, out: &mut Vec<[f64; 2]>) {
+        out.clear();
+        out.extend(
+            self.simplify_indices(tolerance)
+                .into_iter()
+                .map(|index| self.0[index]),
+        );
+    }
+
+    /// Simplify this `LineString` with Douglas-Peucker simplification, always keeping the vertices
+    /// at the indices in `locked`.
+    ///
+    /// The `LineString` is split at the locked vertices and each piece is simplified on its own,
+    /// so a locked vertex can't be removed and the vertices near it are judged against it.
+    /// Indices past the end of the `LineString` are ignored.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Simplify;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [1., 0.1], [2., 0.], [3., 0.1], [4., 0.]]).unwrap();
+    ///
+    /// assert_eq!(line_string.simplify(0.5), LineString::new(vec![[0, 0], [4, 0]]).unwrap());
+    /// assert_eq!(
+    ///     line_string.simplify_locked(0.5, &[2]),
+    ///     LineString::new(vec![[0, 0], [2, 0], [4, 0]]).unwrap()
+    /// );
+    /// ```
+    pub fn simplify_locked(&self, tolerance: f64, locked: &[usize]) -> LineString {
+        let last = self.0.len() - 1;
+        let mut breaks: Vec<usize> = locked
+            .iter()
+            .copied()
+            .filter(|&index| index <= last)
+            .chain([0, last])
+            .collect();
+        breaks.sort_unstable();
+        breaks.dedup();
+
+        let mut coordinates: Vec<[f64; 2]> = vec![self.0[0]];
+        for pair in breaks.windows(2) {
+            let [start, end] = [pair[0], pair[1]];
+            let kept = helpers::douglas_peucker(&self.0[start..=end], tolerance);
+            coordinates.extend(kept.into_iter().skip(1).map(|index| self.0[start + index]));
+        }
+        LineString(coordinates)
+    }
+
+    /// Simplify this `LineString` with a tolerance relative to its size.
+    ///
+    /// The tolerance passed to `simplify` is `fraction` times the length of the diagonal of the
+    /// bounding box, so the same `fraction` gives the same result at any scale.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let block = LineString::new(vec![[0., 0.], [1., 0.1], [2., 0.], [3., 2.], [4., 0.]]).unwrap();
+    /// let continent = block.map_coords(|[x, y]| [x * 1000., y * 1000.]);
+    ///
+    /// let simplified_block = block.simplify_relative(0.05);
+    /// let simplified_continent = continent.simplify_relative(0.05);
+    ///
+    /// assert_eq!(simplified_block, LineString::new(vec![[0, 0], [2, 0], [3, 2], [4, 0]]).unwrap());
+    /// assert_eq!(simplified_continent, simplified_block.map_coords(|[x, y]| [x * 1000., y * 1000.]));
+    /// ```
+    pub fn simplify_relative(&self, fraction: f64) -> LineString {
+        let bounds = self.bounds();
+        let diagonal = (bounds.max_x() - bounds.min_x()).hypot(bounds.max_y() - bounds.min_y());
+        self.simplify(fraction * diagonal)
+    }
+
+    /// Simplify this `LineString` down to at most `max_vertices` vertices.
+    ///
+    /// Uses Visvalingam-Whyatt simplification, repeatedly removing the vertex that forms the
+    /// smallest-area triangle with its neighbours. The first and last vertices are always kept,
+    /// so the result has at least two vertices whatever `max_vertices` is.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![
+    ///     [0., 0.], [1., 0.1], [2., 0.], [3., 5.], [4., 0.],
+    ///     [5., 0.1], [6., 0.], [7., -5.], [8., 0.], [9., 0.1],
+    /// ])
+    /// .unwrap();
+    /// let expected = LineString::new(vec![[0., 0.], [3., 5.], [7., -5.], [9., 0.1]]).unwrap();
+    ///
+    /// assert_eq!(line_string.simplify_to_count(4), expected);
+    /// ```
+    pub fn simplify_to_count(&self, max_vertices: usize) -> LineString {
+        let mut kept: Vec<[f64; 2]> = self.0.clone();
+        while kept.len() > max_vertices.max(2) {
+            let smallest = (1..kept.len() - 1)
+                .map(|index| {
+                    let area = triangle_area(kept[index - 1], kept[index], kept[index + 1]);
+                    (index, area)
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((index, _)) = smallest {
+                kept.remove(index);
+            }
+        }
+        LineString(kept)
+    }
+
+    /// Describe the problems with this `LineString`, rather than just whether it has any.
+    ///
+    /// Segment `i` runs from coordinate `i` to coordinate `i + 1`. Zero-length segments are ignored
+    /// when checking for self-intersections, and the first and last segments of a closed
+    /// `LineString` count as adjacent. Adjacent segments always meet at their shared vertex, so
+    /// they only count as intersecting if the second folds back along the first, overlapping it
+    /// beyond that vertex.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [2, 2], [2, 2], [2, 0], [0, 2]]).unwrap();
+    /// let report = line_string.validation_report();
+    ///
+    /// assert!(report.has_zero_length_segments);
+    /// assert_eq!(report.zero_length_segments, vec![1]);
+    /// assert!(report.has_duplicate_vertices);
+    /// assert_eq!(report.duplicate_vertices, vec![2]);
+    /// assert!(report.is_self_intersecting);
+    /// assert_eq!(report.self_intersections, vec![(0, 3)]);
+    /// assert!(!report.is_closed);
+    ///
+    /// let fold_back = LineString::new(vec![[0, 0], [2, 0], [1, 0]]).unwrap();
+    ///
+    /// assert_eq!(fold_back.validation_report().self_intersections, vec![(0, 1)]);
+    /// ```
+    pub fn validation_report(&self) -> ValidationReport {
+        let segments: Vec<LineSegment> = self
+            .0
+            .windows(2)
+            .map(|pair| LineSegment::new([pair[0], pair[1]]))
+            .collect();
+        let number_of_segments = segments.len();
+        let is_closed = self.is_closed();
+
+        let zero_length_segments: Vec<usize> = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, segment)| segment.0[0] == segment.0[1])
+            .map(|(index, _)| index)
+            .collect();
+
+        let duplicate_vertices: Vec<usize> =
+            zero_length_segments.iter().map(|index| index + 1).collect();
+
+        let non_zero_length_segments: Vec<usize> = (0..number_of_segments)
+            .filter(|index| !zero_length_segments.contains(index))
+            .collect();
+        let number_of_non_zero_length_segments = non_zero_length_segments.len();
+
+        // Whether `second`, which starts where `first` ends, runs back along `first`.
+        let folds_back = |first: &LineSegment, second: &LineSegment| -> bool {
+            let [a, b] = first.0;
+            let c = second.0[1];
+            helpers::cross(a, b, c) == 0f64
+                && helpers::dot([b[0] - a[0], b[1] - a[1]], [c[0] - b[0], c[1] - b[1]]) < 0f64
+        };
+
+        let mut self_intersections: Vec<(usize, usize)> = Vec::new();
+        for i in 0..number_of_non_zero_length_segments {
+            for j in (i + 1)..number_of_non_zero_length_segments {
+                let is_closing_pair =
+                    is_closed && i == 0 && j == number_of_non_zero_length_segments - 1;
+                let segment_i = &segments[non_zero_length_segments[i]];
+                let segment_j = &segments[non_zero_length_segments[j]];
+                let intersects = if j == i + 1 {
+                    folds_back(segment_i, segment_j)
+                } else if is_closing_pair {
+                    folds_back(segment_j, segment_i)
+                } else {
+                    segment_i.intersects(segment_j)
+                };
+                if intersects {
+                    self_intersections
+                        .push((non_zero_length_segments[i], non_zero_length_segments[j]));
+                }
+            }
+        }
+
+        ValidationReport {
+            has_zero_length_segments: !zero_length_segments.is_empty(),
+            zero_length_segments,
+            has_duplicate_vertices: !duplicate_vertices.is_empty(),
+            duplicate_vertices,
+            is_self_intersecting: !self_intersections.is_empty(),
+            self_intersections,
+            is_closed,
+        }
+    }
+
+    /// Return `true` if this `LineString` and another `LineString` have the same number of
+    /// coordinates, in the same order, with each pair of coordinates equal within `eps`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string_1 = LineString::new(vec![[0., 0.], [0.1 + 0.2, 1.]]).unwrap();
+    /// let line_string_2 = LineString::new(vec![[0., 0.], [0.3, 1.]]).unwrap();
+    ///
+    /// assert_ne!(line_string_1, line_string_2);
+    /// assert!(line_string_1.approx_eq(&line_string_2, 1e-9));
+    /// assert!(!line_string_1.approx_eq(&line_string_2, 1e-17));
+    /// ```
+    pub fn approx_eq(&self, other: &LineString, eps: f64) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| approx::points_approx_eq(a, b, eps))
+    }
+
+    /// Return `true` if this `LineString` and another `LineString` cover the same path, within
+    /// `tolerance`, however many vertices each has.
+    ///
+    /// The endpoints must match, in either direction, and every vertex of each `LineString` must
+    /// lie within `tolerance` of the other.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [4, 0], [4, 4]]).unwrap();
+    /// let densified = line_string.densify(0.5);
+    /// let shortcut = LineString::new(vec![[0, 0], [4, 4]]).unwrap();
+    ///
+    /// assert!(!line_string.approx_eq(&densified, 1e-9));
+    /// assert!(line_string.spatially_equals(&densified, 1e-9));
+    /// assert!(!line_string.spatially_equals(&shortcut, 1e-9));
+    /// ```
+    pub fn spatially_equals(&self, other: &LineString, tolerance: f64) -> bool {
+        let near = |a: &[f64; 2], b: &[f64; 2]| distance_between(*a, *b) <= tolerance;
+        let [self_first, self_last] = [&self.0[0], &self.0[self.0.len() - 1]];
+        let [other_first, other_last] = [&other.0[0], &other.0[other.0.len() - 1]];
+        let endpoints_match = (near(self_first, other_first) && near(self_last, other_last))
+            || (near(self_first, other_last) && near(self_last, other_first));
+
+        let covered_by = |line_string: &LineString, coordinates: &[[f64; 2]]| {
+            coordinates.iter().all(|coordinate| {
+                line_string.0.windows(2).any(|pair| {
+                    helpers::distance_to_segment(*coordinate, pair[0], pair[1]) <= tolerance
+                })
+            })
+        };
+
+        endpoints_match && covered_by(self, &other.0) && covered_by(other, &self.0)
+    }
+
+    /// Return the bounding box of this `LineString`, with coordinates as longitude and latitude in
+    /// degrees, allowing for crossings of the antimeridian.
+    ///
+    /// A segment is taken to cross the antimeridian when its longitudes differ by more than 180
+    /// degrees, so it takes the shorter way round. If the `LineString` crosses the antimeridian,
+    /// the returned `Envelope` wraps round it and has `min_x > max_x`: it covers the longitudes
+    /// from `min_x` east to 180 and from -180 east to `max_x`. Its `width` is then negative, so
+    /// add 360 to get the width in degrees. A `LineString` that goes all the way round the
+    /// world covers every longitude from -180 to 180.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::{Bounded, Envelope};
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let crossing = LineString::new(vec![[170, 10], [-170, 20]]).unwrap();
+    /// let bounds = crossing.bounds_geographic();
+    ///
+    /// assert_eq!(
+    ///     [bounds.min_x(), bounds.min_y(), bounds.max_x(), bounds.max_y()],
+    ///     [170., 10., -170., 20.]
+    /// );
+    /// assert_eq!(bounds.width() + 360., 20.);
+    ///
+    /// let not_crossing = LineString::new(vec![[10, 10], [-10, 20]]).unwrap();
+    ///
+    /// assert_eq!(not_crossing.bounds_geographic(), not_crossing.bounds());
+    /// ```
+    pub fn bounds_geographic(&self) -> Envelope {
+        let mut longitude = self.0[0][0];
+        let [mut min_x, mut max_x] = [longitude, longitude];
+        for pair in self.0.windows(2) {
+            let mut step = pair[1][0] - pair[0][0];
+            if step > 180f64 {
+                step -= 360f64;
+            } else if step < -180f64 {
+                step += 360f64;
+            }
+            longitude += step;
+            min_x = min_x.min(longitude);
+            max_x = max_x.max(longitude);
+        }
+
+        let bounds = self.bounds();
+        if max_x - min_x >= 360f64 {
+            [min_x, max_x] = [-180f64, 180f64];
+        } else if max_x > 180f64 {
+            max_x -= 360f64;
+        } else if min_x < -180f64 {
+            min_x += 360f64;
+        }
+        Envelope::from_min_max([min_x, bounds.min_y()], [max_x, bounds.max_y()])
+    }
+}
+
+impl From<LineSegment> for LineString {
+    /// Convert a `LineSegment` into a two-coordinate `LineString`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::{LineSegment, LineString};
+    ///
+    /// let line_string = LineString::from(LineSegment::new([[0, 0], [3, 4]]));
+    ///
+    /// assert_eq!(line_string.wkt(), "LINESTRING (0 0, 3 4)");
+    /// assert_eq!(line_string.length(), 5.);
+    /// ```
+    fn from(line_segment: LineSegment) -> Self {
+        LineString(line_segment.0.to_vec())
+    }
+}
+
+impl Geometry for LineString {
+    /// Compute the geometric center of a geometry.
+    ///
+    /// For a `LineString`, this is the mean of the midpoints of its segments, weighted by their
+    /// lengths. If the `LineString` has zero length, it is the mean of its vertices.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [2, 0], [2, 1]]).unwrap();
+    /// let expected_centroid = Point::new(4. / 3., 1. / 6.);
+    ///
+    /// assert_eq!(line_string.centroid(), expected_centroid);
+    /// ```
+    fn centroid(&self) -> Point {
+        let length = self.length();
+        if length == 0f64 {
+            let number_of_coordinates = self.0.len() as f64;
+            let sum_x: f64 = self.iter().map(|coordinate| coordinate[0]).sum();
+            let sum_y: f64 = self.iter().map(|coordinate| coordinate[1]).sum();
+            return Point::new(sum_x / number_of_coordinates, sum_y / number_of_coordinates);
+        }
+
+        let (sum_x, sum_y) = self
+            .0
+            .windows(2)
+            .fold((0f64, 0f64), |(sum_x, sum_y), pair| {
+                let segment_length = distance_between(pair[0], pair[1]);
+                (
+                    sum_x + segment_length * (pair[0][0] + pair[1][0]) / 2f64,
+                    sum_y + segment_length * (pair[0][1] + pair[1][1]) / 2f64,
+                )
+            });
+        Point::new(sum_x / length, sum_y / length)
+    }
+
+    /// Write the WKT representation of a geometry to `writer`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [1., 1.], [2., 0.5]]).unwrap();
+    /// let expected_wkt = String::from("LINESTRING (0 0, 1 1, 2 0.5)");
+    ///
+    /// assert_eq!(line_string.wkt(), expected_wkt);
+    ///
+    /// let mut buffer = Vec::new();
+    /// line_string.write_wkt(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(buffer).unwrap(), line_string.wkt());
+    /// ```
+    fn write_wkt<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let coordinates = self.iter().format_with(", ", |coordinate, f| {
+            f(&format_args!("{} {}", coordinate[0], coordinate[1]))
+        });
+        write!(writer, "LINESTRING ({})", coordinates)
+    }
+
+    /// Return the WKB representation of a geometry.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::{LineSegment, LineString};
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [1, 2]]).unwrap();
+    /// let wkb = line_string.to_wkb(false);
+    ///
+    /// assert_eq!(wkb.len(), 41);
+    /// assert_eq!(wkb[..9], [0, 0, 0, 0, 2, 0, 0, 0, 2]);
+    /// assert_eq!(wkb[33..], 2f64.to_be_bytes());
+    /// assert_eq!(LineSegment::new([[0, 0], [1, 2]]).to_wkb(false), wkb);
+    /// ```
+    fn to_wkb(&self, little_endian: bool) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(9 + 16 * self.0.len());
+        helpers::write_wkb_header(&mut buffer, little_endian, 2);
+        helpers::write_wkb_u32(&mut buffer, little_endian, self.0.len() as u32);
+        for coordinate in self.iter() {
+            helpers::write_wkb_coordinate(&mut buffer, little_endian, *coordinate);
+        }
+        buffer
+    }
+
+    /// Return a new geometry with `f` applied to every coordinate.
+    ///
+    /// Every mapped `LineString` still has at least two coordinates, but they may no longer be
+    /// distinct. Use `LineString::map_coords_validated` to remove duplicates created by `f`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 1], [2, 3], [4, 5]]).unwrap();
+    /// let expected = LineString::new(vec![[0, 2], [4, 6], [8, 10]]).unwrap();
+    ///
+    /// assert_eq!(line_string.map_coords(|[x, y]| [x * 2., y * 2.]), expected);
+    /// ```
+    fn map_coords<F: Fn([f64; 2]) -> [f64; 2]>(&self, f: F) -> Self {
+        LineString(self.iter().map(|coordinate| f(*coordinate)).collect())
+    }
+
+    /// Return a geometry in a canonical form.
+    ///
+    /// For a `LineString`, consecutive duplicates are removed. A closed `LineString` is then
+    /// turned to run counter-clockwise from its lexicographically smallest vertex, and any other
+    /// `LineString` is reversed if its last vertex is lexicographically smaller than its first.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let ring_1 = LineString::new(vec![[1, 1], [0, 1], [0, 0], [1, 0], [1, 1]]).unwrap();
+    /// let ring_2 = LineString::new(vec![[0, 0], [0, 1], [1, 1], [1, 0], [1, 0], [0, 0]]).unwrap();
+    /// let expected = LineString::new(vec![[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]]).unwrap();
+    ///
+    /// assert_eq!(ring_1.normalize(), expected);
+    /// assert_eq!(ring_2.normalize(), expected);
+    ///
+    /// let line_string = LineString::new(vec![[2, 0], [1, 1], [0, 0]]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     line_string.normalize(),
+    ///     LineString::new(vec![[0, 0], [1, 1], [2, 0]]).unwrap()
+    /// );
+    /// ```
+    fn normalize(&self) -> Self {
+        let mut coordinates = self.dedup().0;
+        let last = coordinates.len() - 1;
+        if coordinates.len() >= 4 && coordinates[0] == coordinates[last] {
+            coordinates.pop();
+            let start = (0..coordinates.len())
+                .min_by(|&a, &b| helpers::cmp_coordinates(&coordinates[a], &coordinates[b]))
+                .unwrap();
+            coordinates.rotate_left(start);
+            let doubled_area: f64 = (0..coordinates.len())
+                .map(|index| {
+                    let next = coordinates[(index + 1) % coordinates.len()];
+                    helpers::cross(coordinates[0], coordinates[index], next)
+                })
+                .sum();
+            if doubled_area < 0f64 {
+                coordinates[1..].reverse();
+            }
+            coordinates.push(coordinates[0]);
+        } else if helpers::cmp_coordinates(&coordinates[last], &coordinates[0]).is_lt() {
+            coordinates.reverse();
+        }
+        LineString(coordinates)
+    }
+}
+
+impl Simplify for LineString {
+    /// Simplify a geometry with Douglas-Peucker simplification.
+    ///
+    /// For a `LineString`, the first and last vertices are always kept, so the result is still a
+    /// valid `LineString`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Simplify;
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0., 0.], [1., 0.1], [2., 0.]]).unwrap();
+    /// let expected = LineString::new(vec![[0., 0.], [2., 0.]]).unwrap();
+    ///
+    /// assert_eq!(line_string.simplify(0.5), expected);
+    /// ```
+    fn simplify(&self, tolerance: f64) -> Self {
+        LineString(
+            self.simplify_indices(tolerance)
+                .into_iter()
+                .map(|index| self.0[index])
+                .collect(),
+        )
+    }
+}
+
+impl Bounded for LineString {
+    /// Return the bounding box of a geometry.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::{Bounded, Envelope};
+    /// use auto_gis_with_rust::line_string::LineString;
+    ///
+    /// let line_string = LineString::new(vec![[0, 0], [2, 3], [1, -1]]).unwrap();
+    ///
+    /// assert_eq!(line_string.bounds(), Envelope::new([[0, -1], [2, 3]]));
+    /// ```
+    fn bounds(&self) -> Envelope {
+        let mut envelope = Envelope::new([self.0[0], self.0[0]]);
+        for coordinate in self.iter() {
+            envelope.expand_to_include(&Point::new(coordinate[0], coordinate[1]));
+        }
+        envelope
+    }
+}
+
+/// `LineString`s are equal if their coordinates are equal and in the same order, so they can be
+/// used in a `HashSet` or as `HashMap` keys. As for `Point`s, every `NaN` is equal to every other
+/// `NaN`.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use auto_gis_with_rust::line_string::LineString;
+///
+/// let line_string = LineString::new(vec![[0, 0], [1, 1], [2, 0]]).unwrap();
+/// let mut line_strings = HashSet::new();
+/// line_strings.insert(line_string.clone());
+/// line_strings.insert(line_string);
+/// line_strings.insert(LineString::new(vec![[-0., 0.], [1., 1.], [2., 0.]]).unwrap());
+///
+/// assert_eq!(line_strings.len(), 1);
+/// ```
+impl Eq for LineString {}
+
+impl Hash for LineString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.0.len());
+        for coordinate in self.iter() {
+            helpers::hash_coordinate(coordinate, state);
+        }
+    }
+}
+
+impl PartialEq for LineString {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for LineString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(helpers::cmp_canonical(&self.0, &other.0))
+    }
+}
+
+/// Builds a `LineString` one coordinate at a time.
+///
+/// A `LineString` needs at least two coordinates, so this collects them first and checks the
+/// length once, in `try_build`.
+#[derive(Debug, Clone, Default)]
+pub struct LineStringBuilder(Vec<[f64; 2]>);
+
+impl LineStringBuilder {
+    /// Construct a new, empty `LineStringBuilder` with room for `capacity` coordinates.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::line_string::LineStringBuilder;
+    ///
+    /// let mut builder = LineStringBuilder::with_capacity(100);
+    /// let capacity = builder.capacity();
+    /// for index in 0..100 {
+    ///     builder.push([index as f64, 0.]);
+    /// }
+    ///
+    /// assert_eq!(builder.capacity(), capacity);
+    /// assert_eq!(builder.try_build().unwrap().iter().count(), 100);
+    /// assert!(LineStringBuilder::with_capacity(1).try_build().is_err());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        LineStringBuilder(Vec::with_capacity(capacity))
+    }
+
+    /// Append `coordinate` to the end of the `LineString` being built.
+    pub fn push(&mut self, coordinate: [f64; 2]) {
+        self.0.push(coordinate);
+    }
+
+    /// Return the number of coordinates this `LineStringBuilder` can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Return the `LineString` built, or an error if it has fewer than two coordinates.
+    pub fn try_build(self) -> Result<LineString, GeometryError> {
+        if self.0.len() < 2 {
+            Err(GeometryError::TooFewCoords(self.0.len()))
+        } else {
+            Ok(LineString(self.0))
+        }
+    }
+}
+
+/// The number of vertices removed by each step of `LineString::clean`.
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct CleanStats {
+    pub duplicates_removed: usize,
+    pub simplified_removed: usize,
+}
+
+/// The problems found by `LineString::validation_report`.
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct ValidationReport {
+    pub has_zero_length_segments: bool,
+    /// The indices of segments whose start and end coordinates are equal.
+    pub zero_length_segments: Vec<usize>,
+    pub has_duplicate_vertices: bool,
+    /// The indices of coordinates that repeat the coordinate before them.
+    pub duplicate_vertices: Vec<usize>,
+    pub is_self_intersecting: bool,
+    /// The index pairs of non-adjacent segments that touch or cross.
+    pub self_intersections: Vec<(usize, usize)>,
+    /// Whether the first and last coordinates are equal, as required for a ring.
+    pub is_closed: bool,
 }
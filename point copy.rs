@@ -1,16 +1,30 @@
 use itertools::Itertools;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::iter::FromIterator;
 use std::slice::Iter;
 
-use crate::geometry::Geometry;
+use crate::envelope::{Bounded, Envelope};
+use crate::error::GeometryError;
+use crate::geometry::{approx, Geometry, Simplify};
 use crate::helpers;
+use crate::line_string::{LineSegment, LineSegments};
+use crate::triangle::{self, Triangle};
 use num_traits::{self, NumCast};
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
+/// The mean radius of the Earth in metres, used for great-circle distances.
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+#[derive(Debug, Clone)]
 pub struct Point([f64; 2]);
 
 impl Point {
     /// Construct a new `Point`.
     ///
+    /// The caller guarantees that the coordinates are finite. Use `new_checked` for coordinates
+    /// from untrusted input.
+    ///
     /// # Examples:
     ///
     /// Construct a new point from x and y floats or x and y integers.
@@ -29,6 +43,28 @@ impl Point {
         Point([x_float, y_float])
     }
 
+    /// Construct a new `Point`, returning an error if either coordinate can't be converted to a
+    /// float or isn't finite.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new_checked(0, 1.5).unwrap(), Point::new(0, 1.5));
+    /// assert!(Point::new_checked(f64::NAN, 1).is_err());
+    /// assert!(Point::new_checked(0, f64::INFINITY).is_err());
+    /// ```
+    pub fn new_checked<T: NumCast, U: NumCast>(x: T, y: U) -> Result<Self, GeometryError> {
+        match (num_traits::cast::<T, f64>(x), num_traits::cast::<U, f64>(y)) {
+            (Some(x), Some(y)) if x.is_finite() && y.is_finite() => Ok(Point([x, y])),
+            (x, y) => Err(GeometryError::InvalidGeometry(format!(
+                "a Point needs finite coordinates, found {:?} and {:?}",
+                x, y
+            ))),
+        }
+    }
+
     pub fn x(&self) -> f64 {
         self.0[0]
     }
@@ -36,87 +72,1355 @@ impl Point {
     pub fn y(&self) -> f64 {
         self.0[1]
     }
-}
 
-impl Geometry for Point {
-    /// Compute the geometric center of a geometry.
+    /// Return the coordinates of this `Point` as an `[x, y]` array.
     ///
-    /// For a `Point`, this is a clone of the `Point` itself.
+    /// # Examples:
     ///
     /// ```
-    /// use auto_gis_with_rust::geometry::Geometry;
     /// use auto_gis_with_rust::point::Point;
     ///
-    /// let point = Point::new(0.0, 1.0);
+    /// assert_eq!(Point::new(1, 2).coords(), [1.0, 2.0]);
+    /// ```
+    pub fn coords(&self) -> [f64; 2] {
+        self.0
+    }
+
+    /// Return the coordinates of this `Point` as an `(x, y)` tuple.
+    ///
+    /// # Examples:
     ///
-    /// assert_eq!(point.centroid(), point);
     /// ```
-    fn centroid(&self) -> Point {
-        self.clone()
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let (x, y) = Point::new(1, 2).to_tuple();
+    ///
+    /// assert_eq!((x, y), (1.0, 2.0));
+    /// ```
+    pub fn to_tuple(&self) -> (f64, f64) {
+        (self.x(), self.y())
     }
 
-    /// Return the WKT representation of a geometry.
+    /// Return the coordinates of this `Point` as an `[x, y]` slice.
     ///
-    /// For a `Point`, this is a clone of the `Point` itself.
+    /// # Examples:
     ///
     /// ```
-    /// use auto_gis_with_rust::geometry::Geometry;
     /// use auto_gis_with_rust::point::Point;
     ///
-    /// let point = Point::new(0.0, 1.0);
-    /// let expected_wkt = String::from("POINT (0.0 1.0")
+    /// assert_eq!(Point::new(1, 2).as_slice(), &[1.0, 2.0]);
+    /// ```
+    pub fn as_slice(&self) -> &[f64] {
+        &self.0
+    }
+
+    /// Return the squared Euclidean distance between this `Point` and another `Point`.
     ///
-    /// assert_eq!(point.wkt(), expected_wkt);
+    /// This avoids the square root in `distance` when only comparing distances.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point_0 = Point::new(0, 0);
+    /// let point_1 = Point::new(3, 4);
+    ///
+    /// assert_eq!(point_0.squared_distance(&point_1), 25f64);
     /// ```
-    fn wkt(&self) -> String {
-        format!("POINT ({} {})", self.x(), self.y())
+    pub fn squared_distance(&self, other: &Point) -> f64 {
+        let dx = other.x() - self.x();
+        let dy = other.y() - self.y();
+        dx * dx + dy * dy
     }
-}
 
-#[derive(Debug, PartialEq, PartialOrd)]
-pub struct MultiPoint(Vec<Point>);
+    /// Return the Euclidean distance between this `Point` and another `Point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point_0 = Point::new(0, 0);
+    /// let point_1 = Point::new(3, 4);
+    ///
+    /// assert_eq!(point_0.distance(&point_1), 5f64);
+    /// ```
+    pub fn distance(&self, other: &Point) -> f64 {
+        self.squared_distance(other).sqrt()
+    }
 
-impl MultiPoint {
-    pub fn new<T: NumCast>(points: Vec<[T; 2]>) -> Self {
-        let float_coordinates = helpers::get_float_coordinates(coordinates);
-        let points: Vec<Point> = float_coordinates
-            .iter()
-            .map(|coordinate| Point::new(coordinate[0], coordinate[1]))
-            .collect();
-        MultiPoint(points)
+    /// Return the Manhattan (L1) distance between this `Point` and another `Point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point_0 = Point::new(0, 0);
+    /// let point_1 = Point::new(3, 4);
+    ///
+    /// assert_eq!(point_0.manhattan_distance(&point_1), 7f64);
+    /// ```
+    pub fn manhattan_distance(&self, other: &Point) -> f64 {
+        (other.x() - self.x()).abs() + (other.y() - self.y()).abs()
     }
 
-    pub fn iter(&self) -> Iter<Point> {
-        self.0.iter()
+    /// Return the Chebyshev (L∞) distance between this `Point` and another `Point`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point_0 = Point::new(0, 0);
+    /// let point_1 = Point::new(3, 4);
+    ///
+    /// assert_eq!(point_0.chebyshev_distance(&point_1), 4f64);
+    /// ```
+    pub fn chebyshev_distance(&self, other: &Point) -> f64 {
+        (other.x() - self.x())
+            .abs()
+            .max((other.y() - self.y()).abs())
     }
-}
 
-impl Geometry for MultiPoint {
-    fn centroid(&self) -> Point {
-        let xs: Vec<f64> = self.iter().map(Point::x).collect();
-        let mean_x: f64 = xs.iter().sum::<f64>() / xs.iter().len() as f64;
+    /// Return the great-circle distance in metres between this `Point` and another `Point`, with
+    /// coordinates as longitude and latitude in degrees.
+    ///
+    /// This uses the haversine formula on a sphere with the mean radius of the Earth.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let helsinki = Point::new(24.9384, 60.1699);
+    /// let tallinn = Point::new(24.7536, 59.4370);
+    ///
+    /// assert!((helsinki.haversine_distance(&tallinn) - 82_000.).abs() < 1_000.);
+    /// ```
+    pub fn haversine_distance(&self, other: &Point) -> f64 {
+        let latitude_1 = self.y().to_radians();
+        let latitude_2 = other.y().to_radians();
+        let half_dlatitude = (latitude_2 - latitude_1) / 2f64;
+        let half_dlongitude = (other.x() - self.x()).to_radians() / 2f64;
+        let a = half_dlatitude.sin().powi(2)
+            + latitude_1.cos() * latitude_2.cos() * half_dlongitude.sin().powi(2);
+        2f64 * EARTH_RADIUS * a.sqrt().asin()
+    }
 
-        let ys: Vec<f64> = self.iter().map(Point::y).collect();
-        let mean_y: f64 = ys.iter().sum::<f64>() / ys.iter().len() as f64;
+    /// Return the interior angle at this `Point`, in radians, between the rays to `from` and `to`.
+    ///
+    /// The angle is in `[0, π]` and doesn't depend on the order of `from` and `to`. If either of
+    /// them is at this `Point`, the angle is zero.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let corner = Point::new(0, 0);
+    ///
+    /// assert_eq!(corner.angle_between(&Point::new(1, 0), &Point::new(0, 1)), FRAC_PI_2);
+    /// assert_eq!(corner.angle_between(&Point::new(-1, 0), &Point::new(1, 0)), PI);
+    /// assert_eq!(corner.angle_between(&Point::new(1, 1), &Point::new(2, 2)), 0.);
+    /// ```
+    pub fn angle_between(&self, from: &Point, to: &Point) -> f64 {
+        let cross = helpers::cross(self.0, from.0, to.0);
+        let dot = helpers::dot(
+            [from.x() - self.x(), from.y() - self.y()],
+            [to.x() - self.x(), to.y() - self.y()],
+        );
+        cross.abs().atan2(dot)
+    }
 
-        Point::new(mean_x, mean_y)
+    /// Snap this `Point` to the nearest corner of a square grid with cells of size `cell`, with
+    /// a corner at `origin`.
+    ///
+    /// A coordinate exactly halfway between two grid lines goes to the one an even number of
+    /// cells from `origin`, so that snapping many points doesn't shift them all in one direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell` isn't positive.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let origin = Point::new(0.5, 0.5);
+    ///
+    /// assert_eq!(Point::new(1.2, 2.9).snap_to_grid(&origin, 1.), Point::new(1.5, 2.5));
+    /// assert_eq!(Point::new(1.0, 2.0).snap_to_grid(&origin, 1.), Point::new(0.5, 2.5));
+    /// assert_eq!(Point::new(7, -3).snap_to_grid(&Point::new(0, 0), 2.), Point::new(8, -4));
+    /// ```
+    pub fn snap_to_grid(&self, origin: &Point, cell: f64) -> Point {
+        assert!(cell > 0f64, "cell must be positive");
+        let snap = |coordinate: f64, origin: f64| -> f64 {
+            origin + ((coordinate - origin) / cell).round_ties_even() * cell
+        };
+        Point::new(snap(self.x(), origin.x()), snap(self.y(), origin.y()))
+    }
+
+    /// Return `true` if this `Point` lies within or on the boundary of `envelope`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let envelope = Envelope::new([[0, 0], [10, 10]]);
+    ///
+    /// assert!(Point::new(5, 10).within(&envelope));
+    /// assert!(!Point::new(5, 11).within(&envelope));
+    /// ```
+    pub fn within(&self, envelope: &Envelope) -> bool {
+        self.x() >= envelope.min_x()
+            && self.x() <= envelope.max_x()
+            && self.y() >= envelope.min_y()
+            && self.y() <= envelope.max_y()
+    }
+
+    /// Return the closest `Point` to this `Point` that lies within `envelope`.
+    ///
+    /// Each coordinate is clamped independently, so a `Point` already inside `envelope` is
+    /// returned unchanged.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let envelope = Envelope::new([[0, 0], [10, 10]]);
+    ///
+    /// assert_eq!(Point::new(12, 15).clamp_to(&envelope), Point::new(10, 10));
+    /// assert_eq!(Point::new(5, 15).clamp_to(&envelope), Point::new(5, 10));
+    /// assert_eq!(Point::new(5, 5).clamp_to(&envelope), Point::new(5, 5));
+    /// ```
+    pub fn clamp_to(&self, envelope: &Envelope) -> Point {
+        Point::new(
+            self.x().clamp(envelope.min_x(), envelope.max_x()),
+            self.y().clamp(envelope.min_y(), envelope.max_y()),
+        )
+    }
+
+    /// Rotate this `Point` clockwise by 90 degrees about the origin.
+    ///
+    /// Like the other right-angle transforms, this swaps and negates coordinates, so it is exact.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(0, 1).rotate_90_cw(), Point::new(1, 0));
+    /// ```
+    pub fn rotate_90_cw(&self) -> Point {
+        Point([self.y(), -self.x()])
+    }
+
+    /// Rotate this `Point` counter-clockwise by 90 degrees about the origin.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(1, 0).rotate_90_ccw(), Point::new(0, 1));
+    /// assert_eq!(Point::new(1, 0).rotate_90_ccw().coords(), [0., 1.]);
+    /// ```
+    pub fn rotate_90_ccw(&self) -> Point {
+        Point([-self.y(), self.x()])
+    }
+
+    /// Negate the x coordinate of this `Point`, mirroring it across the y-axis.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(1, 2).mirror_x(), Point::new(-1, 2));
+    /// ```
+    pub fn mirror_x(&self) -> Point {
+        Point([-self.x(), self.y()])
+    }
+
+    /// Negate the y coordinate of this `Point`, mirroring it across the x-axis.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(1, 2).mirror_y(), Point::new(1, -2));
+    /// ```
+    pub fn mirror_y(&self) -> Point {
+        Point([self.x(), -self.y()])
+    }
+
+    /// Swap the x and y coordinates of this `Point`, for example to turn latitude, longitude into
+    /// longitude, latitude.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(60.17, 24.94).flip(), Point::new(24.94, 60.17));
+    /// ```
+    pub fn flip(&self) -> Point {
+        Point([self.y(), self.x()])
+    }
+
+    /// Linearly interpolate between this `Point` and another `Point`.
+    ///
+    /// `t` is clamped to `[0, 1]`, so the result always lies between the two `Point`s. Use
+    /// `lerp_unclamped` to extrapolate beyond them.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point_0 = Point::new(0, 0);
+    /// let point_1 = Point::new(2, 4);
+    ///
+    /// assert_eq!(point_0.lerp(&point_1, 0.), point_0);
+    /// assert_eq!(point_0.lerp(&point_1, 1.), point_1);
+    /// assert_eq!(point_0.lerp(&point_1, 0.5), Point::new(1, 2));
+    /// assert_eq!(point_0.lerp(&point_1, 1.5), point_1);
+    /// ```
+    pub fn lerp(&self, other: &Point, t: f64) -> Point {
+        self.lerp_unclamped(other, t.clamp(0f64, 1f64))
+    }
+
+    /// Linearly interpolate between this `Point` and another `Point`, without clamping `t`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point_0 = Point::new(0, 0);
+    /// let point_1 = Point::new(2, 4);
+    ///
+    /// assert_eq!(point_0.lerp_unclamped(&point_1, 1.5), Point::new(3, 6));
+    /// assert_eq!(point_0.lerp_unclamped(&point_1, -0.5), Point::new(-1, -2));
+    /// ```
+    pub fn lerp_unclamped(&self, other: &Point, t: f64) -> Point {
+        Point::new(
+            self.x() + t * (other.x() - self.x()),
+            self.y() + t * (other.y() - self.y()),
+        )
+    }
+
+    /// Return a copy of this `Point` moved by a random offset of at most `max_offset`.
+    ///
+    /// The offset is drawn uniformly from the disc of radius `max_offset` around this `Point`.
+    /// Requires the `rand` feature.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(1, 2);
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// for _ in 0..100 {
+    ///     assert!(point.jitter(0.5, &mut rng).distance(&point) <= 0.5);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn jitter(&self, max_offset: f64, rng: &mut impl rand::Rng) -> Point {
+        let angle = rng.gen::<f64>() * std::f64::consts::TAU;
+        let radius = max_offset * rng.gen::<f64>().sqrt();
+        Point::new(
+            self.x() + radius * angle.cos(),
+            self.y() + radius * angle.sin(),
+        )
+    }
+
+    /// Compare this `Point` with another `Point` by x, then by y.
+    ///
+    /// This is a total order, using `f64::total_cmp`, so it can be used with `sort_by`. Unlike
+    /// `partial_cmp`, it orders `-0.0` before `0.0` and tells `NaN`s apart.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let mut points = vec![
+    ///     Point::new(1, 0),
+    ///     Point::new(0, 2),
+    ///     Point::new(-1, 5),
+    ///     Point::new(0, 1),
+    ///     Point::new(1, -1),
+    /// ];
+    /// points.sort_by(|a, b| a.cmp_lexicographic(b));
+    /// let expected = vec![
+    ///     Point::new(-1, 5),
+    ///     Point::new(0, 1),
+    ///     Point::new(0, 2),
+    ///     Point::new(1, -1),
+    ///     Point::new(1, 0),
+    /// ];
+    ///
+    /// assert_eq!(points, expected);
+    /// ```
+    pub fn cmp_lexicographic(&self, other: &Point) -> Ordering {
+        helpers::cmp_coordinates(&self.0, &other.0)
+    }
+
+    /// Return `true` if the coordinates of this `Point` and another `Point` each differ by at
+    /// most `eps`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point_0 = Point::new(0.1 + 0.2, 1.);
+    /// let point_1 = Point::new(0.3, 1.);
+    ///
+    /// assert_ne!(point_0, point_1);
+    /// assert!(point_0.approx_eq(&point_1, 1e-9));
+    /// ```
+    pub fn approx_eq(&self, other: &Point, eps: f64) -> bool {
+        approx::points_approx_eq(&self.0, &other.0, eps)
     }
+}
 
-    /// Return the WKT representation of a geometry.
+impl Geometry for Point {
+    /// Compute the geometric center of a geometry.
+    ///
+    /// For a `Point`, this is a clone of the `Point` itself.
     ///
     /// ```
     /// use auto_gis_with_rust::geometry::Geometry;
-    /// use auto_gis_with_rust::point::MultiPoint;
+    /// use auto_gis_with_rust::point::Point;
     ///
-    /// let line_segment = LineSegment::new([[0., 0.], [1., 1.]]);
-    /// let expected_wkt = String::from("LINESTRING (0 0, 1 1)");
+    /// let point = Point::new(0.0, 1.0);
     ///
-    /// assert_eq!(line_segment.wkt(), expected_wkt);
+    /// assert_eq!(point.centroid(), point);
     /// ```
-    fn wkt(&self) -> String {
-        let points = self.iter().format_with(", ", |point, f| {
-            f(&format_args!("{} {}", point.x(), point.y()))
-        });
-        format!("MULTIPOINT ({})", points)
+    fn centroid(&self) -> Point {
+        self.clone()
+    }
+
+    /// Write the WKT representation of a geometry to `writer`.
+    ///
+    /// Coordinates are always written in fixed decimal notation, never scientific notation, with
+    /// the fewest digits that read back as the same `f64`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(0.0, 1.0);
+    /// let expected_wkt = String::from("POINT (0 1)");
+    ///
+    /// assert_eq!(point.wkt(), expected_wkt);
+    ///
+    /// let point = Point::new(0.0000001, 1e21);
+    /// let expected_wkt = String::from("POINT (0.0000001 1000000000000000000000)");
+    ///
+    /// assert_eq!(point.wkt(), expected_wkt);
+    ///
+    /// let point = Point::new(0.1 + 0.2, 1. / 3.);
+    /// let expected_wkt = String::from("POINT (0.30000000000000004 0.3333333333333333)");
+    ///
+    /// assert_eq!(point.wkt(), expected_wkt);
+    /// ```
+    fn write_wkt<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "POINT ({} {})", self.x(), self.y())
+    }
+
+    /// Return the WKB representation of a geometry.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(1, 2);
+    /// let mut expected = vec![1, 1, 0, 0, 0];
+    /// expected.extend_from_slice(&1f64.to_le_bytes());
+    /// expected.extend_from_slice(&2f64.to_le_bytes());
+    ///
+    /// assert_eq!(point.to_wkb(true), expected);
+    /// assert_eq!(&point.to_wkb(false)[..5], [0, 0, 0, 0, 1]);
+    /// ```
+    fn to_wkb(&self, little_endian: bool) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(21);
+        helpers::write_wkb_header(&mut buffer, little_endian, 1);
+        helpers::write_wkb_coordinate(&mut buffer, little_endian, self.0);
+        buffer
+    }
+
+    /// Return a new geometry with `f` applied to every coordinate.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(1, 2);
+    ///
+    /// assert_eq!(point.map_coords(|[x, y]| [x * 2., y * 2.]), Point::new(2, 4));
+    /// ```
+    fn map_coords<F: Fn([f64; 2]) -> [f64; 2]>(&self, f: F) -> Self {
+        Point(f(self.0))
+    }
+
+    /// Return a geometry in a canonical form.
+    ///
+    /// A `Point` is already in its canonical form, so this returns a copy.
+    fn normalize(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl From<Point> for (f64, f64) {
+    /// Convert a `Point` into an `(x, y)` tuple.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let (x, y): (f64, f64) = Point::new(1, 2).into();
+    ///
+    /// assert_eq!((x, y), (1.0, 2.0));
+    /// ```
+    fn from(point: Point) -> Self {
+        point.to_tuple()
+    }
+}
+
+impl From<&Point> for [f64; 2] {
+    /// Convert a reference to a `Point` into an `[x, y]` array.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// let point = Point::new(1, 2);
+    /// let coordinates: [f64; 2] = (&point).into();
+    ///
+    /// assert_eq!(coordinates, [1.0, 2.0]);
+    /// ```
+    fn from(point: &Point) -> Self {
+        point.coords()
+    }
+}
+
+impl Simplify for Point {
+    /// Simplify a geometry with Douglas-Peucker simplification.
+    ///
+    /// A `Point` has nothing to remove, so it is returned unchanged.
+    fn simplify(&self, _tolerance: f64) -> Self {
+        self.clone()
+    }
+}
+
+impl Bounded for Point {
+    /// Return the bounding box of a geometry.
+    ///
+    /// For a `Point`, this is a zero-area `Envelope` at the `Point`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::{Bounded, Envelope};
+    /// use auto_gis_with_rust::point::Point;
+    ///
+    /// assert_eq!(Point::new(1, 2).bounds(), Envelope::new([[1, 2], [1, 2]]));
+    /// ```
+    fn bounds(&self) -> Envelope {
+        Envelope::new([self.0, self.0])
+    }
+}
+
+/// `Point`s are equal if their coordinates are equal, so they can be used in a `HashSet` or as
+/// `HashMap` keys.
+///
+/// Unlike for `f64`s, every `NaN` is equal to every other `NaN`, so every `Point` is equal to
+/// itself. `-0.0` is still equal to `0.0`. `Point`s are ordered by x, then by y, with `NaN` after
+/// infinity.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use auto_gis_with_rust::point::Point;
+///
+/// let point = Point::new(0, 1);
+/// let points: HashSet<Point> = vec![point.clone(), point, Point::new(-0., 1.)].into_iter().collect();
+///
+/// assert_eq!(points.len(), 1);
+/// assert_eq!(Point::new(f64::NAN, 1.), Point::new(-f64::NAN, 1.));
+/// assert!(Point::new(f64::NAN, 1.) > Point::new(f64::INFINITY, 1.));
+/// ```
+impl Eq for Point {}
+
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        helpers::hash_coordinate(&self.0, state);
+    }
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(helpers::cmp_canonical(&[self.0], &[other.0]))
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct MultiPoint(Vec<Point>);
+
+impl MultiPoint {
+    pub fn new<T: NumCast>(coordinates: Vec<[T; 2]>) -> Self {
+        let float_coordinates = helpers::get_float_coordinates(coordinates);
+        let points: Vec<Point> = float_coordinates
+            .iter()
+            .map(|coordinate| Point::new(coordinate[0], coordinate[1]))
+            .collect();
+        MultiPoint(points)
+    }
+
+    /// Construct a new, empty `MultiPoint` with room for `capacity` `Point`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let mut multi_point = MultiPoint::with_capacity(100);
+    /// let capacity = multi_point.capacity();
+    /// for index in 0..100 {
+    ///     multi_point.push(Point::new(index, 0));
+    /// }
+    ///
+    /// assert_eq!(multi_point.capacity(), capacity);
+    /// assert_eq!(multi_point.iter().count(), 100);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        MultiPoint(Vec::with_capacity(capacity))
+    }
+
+    /// Append `point` to this `MultiPoint`.
+    pub fn push(&mut self, point: Point) {
+        self.0.push(point);
+    }
+
+    /// Return the number of `Point`s this `MultiPoint` can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Construct a new `MultiPoint`, returning an error instead of panicking if a coordinate
+    /// can't be converted to a float.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point_1 = MultiPoint::try_new(vec![[0, 0], [1, 2]]).unwrap();
+    /// let multi_point_2 = MultiPoint::new(vec![[0, 0], [1, 2]]);
+    ///
+    /// assert_eq!(multi_point_1, multi_point_2);
+    /// ```
+    pub fn try_new<T: NumCast>(coordinates: Vec<[T; 2]>) -> Result<Self, GeometryError> {
+        let float_coordinates = helpers::try_get_float_coordinates(coordinates)?;
+        Ok(float_coordinates
+            .iter()
+            .map(|coordinate| Point::new(coordinate[0], coordinate[1]))
+            .collect())
+    }
+
+    /// Parse a `MultiPoint` from WKT.
+    ///
+    /// Both OGC forms are accepted, with or without parentheses around each point, as is
+    /// `MULTIPOINT EMPTY`. The geometry tag is case-insensitive. Malformed WKT, including a
+    /// non-finite coordinate such as `NaN` or `inf`, is a `GeometryError::ParseError`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::error::GeometryError;
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let bracketed = MultiPoint::from_wkt("MULTIPOINT ((1 2), (3 4.5))").unwrap();
+    /// let unbracketed = MultiPoint::from_wkt("MULTIPOINT (1 2, 3 4.5)").unwrap();
+    ///
+    /// assert_eq!(bracketed, MultiPoint::new(vec![[1., 2.], [3., 4.5]]));
+    /// assert_eq!(bracketed, unbracketed);
+    /// assert!(MultiPoint::from_wkt("MULTIPOINT (1 2, 3)").is_err());
+    /// assert!(MultiPoint::from_wkt("MULTIPOINT (1 2, NaN 4)").is_err());
+    /// assert!(MultiPoint::from_wkt("POINT (1 2)").is_err());
+    /// assert!(matches!(
+    ///     MultiPoint::from_wkt("MULTIPOINT (NaN 1)"),
+    ///     Err(GeometryError::ParseError(_))
+    /// ));
+    /// ```
+    pub fn from_wkt(wkt: &str) -> Result<Self, GeometryError> {
+        let error = |message: &str| GeometryError::ParseError(format!("{}: {:?}", message, wkt));
+
+        let wkt = wkt.trim();
+        let tag = wkt
+            .get(..10)
+            .filter(|tag| tag.eq_ignore_ascii_case("MULTIPOINT"))
+            .ok_or_else(|| error("expected MULTIPOINT"))?;
+        let body = wkt[tag.len()..].trim();
+        if body.eq_ignore_ascii_case("EMPTY") {
+            return Ok(MultiPoint(Vec::new()));
+        }
+        let body = body
+            .strip_prefix('(')
+            .and_then(|body| body.strip_suffix(')'))
+            .ok_or_else(|| error("expected parentheses around the points"))?;
+
+        body.split(',')
+            .map(|point| {
+                let point = point.trim();
+                let point = point
+                    .strip_prefix('(')
+                    .and_then(|point| point.strip_suffix(')'))
+                    .unwrap_or(point);
+                let coordinates: Vec<f64> = point
+                    .split_whitespace()
+                    .map(|coordinate| coordinate.parse::<f64>())
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| error("invalid coordinate"))?;
+                match coordinates[..] {
+                    [x, y] => Point::new_checked(x, y).map_err(|_| error("non-finite coordinate")),
+                    _ => Err(error("expected two coordinates per point")),
+                }
+            })
+            .collect()
+    }
+
+    /// Construct a new `MultiPoint` from a vector of `Point`s.
+    ///
+    /// Unlike `MultiPoint::new` and `collect`, this rejects an empty vector.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point_1 = MultiPoint::from_points(vec![Point::new(0, 0), Point::new(1, 2)]).unwrap();
+    /// let multi_point_2 = MultiPoint::new(vec![[0, 0], [1, 2]]);
+    ///
+    /// assert_eq!(multi_point_1.wkt(), multi_point_2.wkt());
+    /// assert!(MultiPoint::from_points(Vec::new()).is_err());
+    /// ```
+    pub fn from_points(points: Vec<Point>) -> Result<Self, GeometryError> {
+        if points.is_empty() {
+            Err(GeometryError::InvalidGeometry(String::from(
+                "a MultiPoint needs at least one Point",
+            )))
+        } else {
+            Ok(MultiPoint(points))
+        }
+    }
+
+    pub fn iter(&self) -> Iter<Point> {
+        self.0.iter()
+    }
+
+    /// Return the index, a reference to, and the distance of the closest `Point` to `point`.
+    ///
+    /// Returns `None` if this `MultiPoint` is empty.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 0], [10, 0], [4, 3]]);
+    /// let (index, nearest, distance) = multi_point.nearest(&Point::new(8, 3)).unwrap();
+    ///
+    /// assert_eq!(index, 1);
+    /// assert_eq!(nearest, &Point::new(10, 0));
+    /// assert_eq!(distance, 13f64.sqrt());
+    /// ```
+    pub fn nearest(&self, point: &Point) -> Option<(usize, &Point, f64)> {
+        self.iter()
+            .enumerate()
+            .map(|(index, candidate)| (index, candidate, candidate.squared_distance(point)))
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(index, nearest, squared_distance)| (index, nearest, squared_distance.sqrt()))
+    }
+
+    /// Compute the mean of the `Point`s in this `MultiPoint`, weighted by `weights`.
+    ///
+    /// Returns an error if there isn't exactly one weight per `Point` or if the weights sum to
+    /// zero.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 0], [4, 0]]);
+    ///
+    /// assert_eq!(multi_point.weighted_centroid(&[1., 3.]).unwrap(), Point::new(3, 0));
+    /// assert!(multi_point.weighted_centroid(&[1.]).is_err());
+    /// assert!(multi_point.weighted_centroid(&[0., 0.]).is_err());
+    /// ```
+    pub fn weighted_centroid(&self, weights: &[f64]) -> Result<Point, GeometryError> {
+        if weights.len() != self.0.len() {
+            return Err(GeometryError::InvalidGeometry(format!(
+                "expected {} weights, found {}",
+                self.0.len(),
+                weights.len()
+            )));
+        }
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight == 0f64 {
+            return Err(GeometryError::InvalidGeometry(String::from(
+                "weights sum to zero",
+            )));
+        }
+
+        let sum_x: f64 = self
+            .iter()
+            .zip(weights)
+            .map(|(point, weight)| point.x() * weight)
+            .sum();
+        let sum_y: f64 = self
+            .iter()
+            .zip(weights)
+            .map(|(point, weight)| point.y() * weight)
+            .sum();
+        Ok(Point::new(sum_x / total_weight, sum_y / total_weight))
+    }
+
+    /// Return the Euclidean distance between every pair of `Point`s in this `MultiPoint`.
+    ///
+    /// Row `i`, column `j` holds the distance from `Point` `i` to `Point` `j`, so the matrix is
+    /// symmetric with a zero diagonal. Computing and storing it takes O(n²) time and memory, so
+    /// prefer `index::KdTree` for nearest-neighbour queries on large `MultiPoint`s.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 0], [3, 0], [3, 4]]);
+    /// let matrix = multi_point.distance_matrix();
+    ///
+    /// assert_eq!(matrix, vec![vec![0., 3., 5.], vec![3., 0., 4.], vec![5., 4., 0.]]);
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         assert_eq!(matrix[i][j], matrix[j][i]);
+    ///     }
+    /// }
+    /// ```
+    pub fn distance_matrix(&self) -> Vec<Vec<f64>> {
+        self.pairwise(Point::distance)
+    }
+
+    /// Return the great-circle distance in metres between every pair of `Point`s in this
+    /// `MultiPoint`, with coordinates as longitude and latitude in degrees.
+    ///
+    /// See `distance_matrix` for the layout and cost, and `Point::haversine_distance` for the
+    /// distance.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0., 0.], [0., 1.], [1., 0.]]);
+    /// let matrix = multi_point.haversine_distance_matrix();
+    ///
+    /// assert_eq!(matrix[0][0], 0.);
+    /// assert_eq!(matrix[0][1], matrix[1][0]);
+    /// assert!((matrix[0][1] - 111_195.).abs() < 1.);
+    /// assert!((matrix[0][1] - matrix[0][2]).abs() < 1e-6);
+    /// ```
+    pub fn haversine_distance_matrix(&self) -> Vec<Vec<f64>> {
+        self.pairwise(Point::haversine_distance)
+    }
+
+    /// Return the edges of the Euclidean minimum spanning tree of this `MultiPoint`.
+    ///
+    /// The tree connects every `Point` with the shortest total edge length. It is built with
+    /// Prim's algorithm over `distance_matrix`, so it takes O(n²) time and memory. Each edge runs
+    /// from a `Point` already in the tree to the `Point` it adds.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let square = MultiPoint::new(vec![[0, 0], [1, 0], [1, 1], [0, 1]]);
+    /// let edges: Vec<_> = square.euclidean_mst().into_iter().collect();
+    /// let total_length: f64 = edges
+    ///     .iter()
+    ///     .map(|edge| edge.source().distance(&edge.target()))
+    ///     .sum();
+    ///
+    /// assert_eq!(edges.len(), 3);
+    /// assert_eq!(total_length, 3.);
+    /// ```
+    pub fn euclidean_mst(&self) -> LineSegments {
+        let matrix = self.distance_matrix();
+        let number_of_points = self.0.len();
+        if number_of_points == 0 {
+            return LineSegments::from_iter(Vec::new());
+        }
+
+        let mut in_tree = vec![false; number_of_points];
+        // The shortest known distance from each point to the tree, and the tree point it is from.
+        let mut nearest: Vec<(f64, usize)> = vec![(f64::INFINITY, 0); number_of_points];
+        let mut edges: Vec<LineSegment> = Vec::with_capacity(number_of_points - 1);
+        let mut added = 0;
+        for _ in 0..number_of_points {
+            in_tree[added] = true;
+            if added != 0 {
+                let from = nearest[added].1;
+                edges.push(LineSegment::new([
+                    self.0[from].coords(),
+                    self.0[added].coords(),
+                ]));
+            }
+            for (index, distance) in matrix[added].iter().enumerate() {
+                if !in_tree[index] && *distance < nearest[index].0 {
+                    nearest[index] = (*distance, added);
+                }
+            }
+            if let Some(next) = (0..number_of_points)
+                .filter(|&index| !in_tree[index])
+                .min_by(|&a, &b| nearest[a].0.total_cmp(&nearest[b].0))
+            {
+                added = next;
+            }
+        }
+        edges.into_iter().collect()
+    }
+
+    /// Return the Delaunay triangulation of this `MultiPoint`.
+    ///
+    /// No `Point` lies inside the circumcircle of any of the triangles, which are built with the
+    /// Bowyer-Watson algorithm in O(n²) time. Duplicate `Point`s are used once, and if there are
+    /// fewer than three distinct `Point`s or they are all collinear there are no triangles.
+    /// Where four or more `Point`s lie on one circle, as the corners of a square do, any of the
+    /// equally valid triangulations may be returned.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let square = MultiPoint::new(vec![[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]]);
+    /// let triangles = square.delaunay();
+    ///
+    /// assert_eq!(triangles.len(), 2);
+    /// assert_eq!(triangles.iter().map(|triangle| triangle.area()).sum::<f64>(), 1.);
+    ///
+    /// let collinear = MultiPoint::new(vec![[0, 0], [1, 1], [2, 2]]);
+    ///
+    /// assert!(collinear.delaunay().is_empty());
+    /// ```
+    ///
+    /// The triangles always cover the convex hull, however thin it is:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let area = |coordinates: Vec<[f64; 2]>| -> f64 {
+    ///     let triangles = MultiPoint::new(coordinates).delaunay();
+    ///     triangles.iter().map(|triangle| triangle.area()).sum()
+    /// };
+    ///
+    /// // Scattered points inside a square, along with its corners.
+    /// let mut scattered: Vec<[f64; 2]> = (0..200)
+    ///     .map(|i| [(i * 37 % 101) as f64, (i * 59 % 97) as f64 * 100. / 97.])
+    ///     .collect();
+    /// scattered.extend([[0., 0.], [100., 0.], [100., 100.], [0., 100.]]);
+    /// assert!((area(scattered) - 10000.).abs() < 1e-6);
+    ///
+    /// let near_collinear = vec![[0., 0.], [1., 0.], [2., 0.], [3., 0.], [1.5, 0.01]];
+    /// assert!((area(near_collinear) - 0.015).abs() < 1e-12);
+    ///
+    /// let long_and_thin: Vec<[f64; 2]> = (0..40).map(|i| [(i / 2 * 5) as f64, (i % 2) as f64]).collect();
+    /// assert!((area(long_and_thin) - 95.).abs() < 1e-9);
+    /// ```
+    pub fn delaunay(&self) -> Vec<Triangle> {
+        let coordinates: Vec<[f64; 2]> = self.iter().map(|point| point.coords()).collect();
+        triangle::delaunay(&coordinates)
+    }
+
+    /// Count the `Point`s of this `MultiPoint` in each cell of a regular grid over `envelope`.
+    ///
+    /// The grid has `cols` columns and `rows` rows of equal cells, and the count for the cell in
+    /// row `r` and column `c` is at `[r][c]`, with row 0 along the bottom (minimum y) edge and
+    /// column 0 along the left (minimum x) edge. `Point`s on a boundary between cells are counted
+    /// in the cell above or to the right, except on the top and right edges of `envelope`.
+    /// `Point`s outside `envelope` are ignored.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::Envelope;
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::new(vec![[1, 1], [3, 1], [3, 3], [2, 3], [4, 4], [5, 5]]);
+    /// let counts = multi_point.bin(&Envelope::new([[0, 0], [4, 4]]), 2, 2);
+    ///
+    /// assert_eq!(counts, vec![vec![1, 1], vec![0, 3]]);
+    /// ```
+    pub fn bin(&self, envelope: &Envelope, cols: usize, rows: usize) -> Vec<Vec<usize>> {
+        let mut counts = vec![vec![0; cols]; rows];
+        if cols == 0 || rows == 0 {
+            return counts;
+        }
+        let cell = |coordinate: f64, min: f64, size: f64, cells: usize| -> usize {
+            let cell = ((coordinate - min) / size * cells as f64).floor();
+            if cell.is_finite() {
+                (cell as usize).min(cells - 1)
+            } else {
+                0
+            }
+        };
+        for point in self.iter().filter(|point| point.within(envelope)) {
+            let col = cell(point.x(), envelope.min_x(), envelope.width(), cols);
+            let row = cell(point.y(), envelope.min_y(), envelope.height(), rows);
+            counts[row][col] += 1;
+        }
+        counts
+    }
+
+    fn pairwise<F: Fn(&Point, &Point) -> f64>(&self, distance: F) -> Vec<Vec<f64>> {
+        let mut matrix = vec![vec![0f64; self.0.len()]; self.0.len()];
+        for (i, point_i) in self.iter().enumerate() {
+            for (j, point_j) in self.iter().enumerate().skip(i + 1) {
+                let d = distance(point_i, point_j);
+                matrix[i][j] = d;
+                matrix[j][i] = d;
+            }
+        }
+        matrix
+    }
+
+    /// Return `true` if this `MultiPoint` and another `MultiPoint` hold the same points in any order.
+    ///
+    /// Each point is greedily paired with an equal unpaired point of `other` if there is one, and
+    /// otherwise with the nearest, and the pair matches if they are within `tolerance` of each
+    /// other (or only if equal when `tolerance` is `None`). Points are equal as for `==`, so a
+    /// `NaN` point pairs with another `NaN` point. Use `==` to also compare the order of the
+    /// points.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point_1 = MultiPoint::new(vec![[0, 0], [1, 1]]);
+    /// let multi_point_2 = MultiPoint::new(vec![[1, 1], [0, 0]]);
+    /// let multi_point_3 = MultiPoint::new(vec![[1.001, 1.], [0., 0.]]);
+    ///
+    /// assert_ne!(multi_point_1, multi_point_2);
+    /// assert!(multi_point_1.set_eq(&multi_point_2, None));
+    /// assert!(!multi_point_1.set_eq(&multi_point_3, None));
+    /// assert!(multi_point_1.set_eq(&multi_point_3, Some(0.01)));
+    ///
+    /// let with_nan = MultiPoint::new(vec![[f64::NAN, 1.], [0., 0.]]);
+    ///
+    /// assert!(with_nan.set_eq(&with_nan, None));
+    /// assert!(with_nan.set_eq(&MultiPoint::new(vec![[0., 0.], [f64::NAN, 1.]]), Some(0.01)));
+    /// assert!(!with_nan.set_eq(&multi_point_1, Some(0.01)));
+    /// ```
+    pub fn set_eq(&self, other: &MultiPoint, tolerance: Option<f64>) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+        let tolerance = tolerance.unwrap_or(0f64);
+        let mut unpaired: Vec<&Point> = other.iter().collect();
+        self.iter().all(|point| {
+            let equal = unpaired.iter().position(|candidate| {
+                helpers::cmp_canonical(&[candidate.0], &[point.0]) == Ordering::Equal
+            });
+            let nearest = equal.map(|index| (index, 0f64)).or_else(|| {
+                unpaired
+                    .iter()
+                    .enumerate()
+                    .map(|(index, candidate)| (index, candidate.distance(point)))
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+            });
+            match nearest {
+                Some((index, distance)) if distance <= tolerance => {
+                    unpaired.swap_remove(index);
+                    true
+                }
+                _ => false,
+            }
+        })
+    }
+
+    /// Return `true` if this `MultiPoint` and another `MultiPoint` hold the same number of points,
+    /// in the same order, with each pair of points equal within `eps`.
+    ///
+    /// Use `set_eq` to ignore the order of the points.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point_1 = MultiPoint::new(vec![[0.1 + 0.2, 0.], [1., 1.]]);
+    /// let multi_point_2 = MultiPoint::new(vec![[0.3, 0.], [1., 1.]]);
+    ///
+    /// assert_ne!(multi_point_1, multi_point_2);
+    /// assert!(multi_point_1.approx_eq(&multi_point_2, 1e-9));
+    /// ```
+    pub fn approx_eq(&self, other: &MultiPoint, eps: f64) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq(b, eps))
+    }
+}
+
+impl Simplify for MultiPoint {
+    /// Simplify a geometry with Douglas-Peucker simplification.
+    ///
+    /// The `Point`s of a `MultiPoint` aren't joined, so none of them can be removed without
+    /// changing its shape and it is returned unchanged.
+    fn simplify(&self, _tolerance: f64) -> Self {
+        MultiPoint(self.0.clone())
+    }
+}
+
+impl FromIterator<Point> for MultiPoint {
+    /// Collect `Point`s into a `MultiPoint`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point: MultiPoint = (0..3).map(|i| Point::new(i, i * 2)).collect();
+    ///
+    /// assert_eq!(multi_point, MultiPoint::new(vec![[0, 0], [1, 2], [2, 4]]));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Point>>(points: I) -> Self {
+        MultiPoint(points.into_iter().collect())
+    }
+}
+
+impl Geometry for MultiPoint {
+    /// Compute the geometric center of a geometry.
+    ///
+    /// For a `MultiPoint`, this is the mean of its `Point`s. The coordinates are `NaN` if it is
+    /// empty, see `try_centroid`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 0], [2, 1]]);
+    ///
+    /// assert_eq!(multi_point.centroid(), Point::new(1., 0.5));
+    /// ```
+    fn centroid(&self) -> Point {
+        let xs: Vec<f64> = self.iter().map(Point::x).collect();
+        let mean_x: f64 = xs.iter().sum::<f64>() / xs.iter().len() as f64;
+
+        let ys: Vec<f64> = self.iter().map(Point::y).collect();
+        let mean_y: f64 = ys.iter().sum::<f64>() / ys.iter().len() as f64;
+
+        Point::new(mean_x, mean_y)
+    }
+
+    /// Compute the geometric center of a geometry, or `None` if it is empty.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 0], [2, 1]]);
+    /// let empty = MultiPoint::new(Vec::<[f64; 2]>::new());
+    ///
+    /// assert_eq!(multi_point.try_centroid(), Some(Point::new(1., 0.5)));
+    /// assert_eq!(empty.try_centroid(), None);
+    /// ```
+    fn try_centroid(&self) -> Option<Point> {
+        if self.0.is_empty() {
+            None
+        } else {
+            Some(self.centroid())
+        }
+    }
+
+    /// Write the WKT representation of a geometry to `writer`.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0., 0.], [1., 0.0000001]]);
+    /// let expected_wkt = String::from("MULTIPOINT (0 0, 1 0.0000001)");
+    ///
+    /// assert_eq!(multi_point.wkt(), expected_wkt);
+    /// ```
+    fn write_wkt<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let points = self.iter().format_with(", ", |point, f| {
+            f(&format_args!("{} {}", point.x(), point.y()))
+        });
+        write!(writer, "MULTIPOINT ({})", points)
+    }
+
+    /// Return the WKB representation of a geometry.
+    ///
+    /// For a `MultiPoint`, this is the number of `Point`s followed by the WKB of each of them.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::{MultiPoint, Point};
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 0], [1, 2]]);
+    /// let wkb = multi_point.to_wkb(true);
+    ///
+    /// assert_eq!(wkb[..9], [1, 4, 0, 0, 0, 2, 0, 0, 0]);
+    /// assert_eq!(wkb[30..], Point::new(1, 2).to_wkb(true));
+    /// ```
+    fn to_wkb(&self, little_endian: bool) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(9 + 21 * self.0.len());
+        helpers::write_wkb_header(&mut buffer, little_endian, 4);
+        helpers::write_wkb_u32(&mut buffer, little_endian, self.0.len() as u32);
+        for point in self.iter() {
+            buffer.extend(point.to_wkb(little_endian));
+        }
+        buffer
+    }
+
+    /// Return the WKT representation of a geometry, with each part on its own indented line.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0., 0.], [1., 0.5]]);
+    /// let expected_wkt = String::from("MULTIPOINT (\n    0 0,\n    1 0.5\n)");
+    ///
+    /// assert_eq!(multi_point.wkt_pretty(), expected_wkt);
+    /// ```
+    fn wkt_pretty(&self) -> String {
+        let points = self.iter().format_with(",\n", |point, f| {
+            f(&format_args!("    {} {}", point.x(), point.y()))
+        });
+        format!("MULTIPOINT (\n{}\n)", points)
+    }
+
+    /// Return a new geometry with `f` applied to every coordinate.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::new(vec![[0, 1], [2, 3]]);
+    /// let expected = MultiPoint::new(vec![[0, 2], [4, 6]]);
+    ///
+    /// assert_eq!(multi_point.map_coords(|[x, y]| [x * 2., y * 2.]), expected);
+    /// ```
+    fn map_coords<F: Fn([f64; 2]) -> [f64; 2]>(&self, f: F) -> Self {
+        MultiPoint(self.iter().map(|point| point.map_coords(&f)).collect())
+    }
+
+    /// Return a geometry in a canonical form.
+    ///
+    /// For a `MultiPoint`, the `Point`s are sorted lexicographically and duplicates are removed.
+    ///
+    /// ```
+    /// use auto_gis_with_rust::geometry::Geometry;
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point_1 = MultiPoint::new(vec![[1, 1], [0, 2], [0, 1], [1, 1]]);
+    /// let multi_point_2 = MultiPoint::new(vec![[0, 1], [1, 1], [0, 2]]);
+    ///
+    /// assert_ne!(multi_point_1, multi_point_2);
+    /// assert_eq!(multi_point_1.normalize(), multi_point_2.normalize());
+    /// assert_eq!(
+    ///     multi_point_2.normalize(),
+    ///     MultiPoint::new(vec![[0, 1], [0, 2], [1, 1]])
+    /// );
+    /// ```
+    fn normalize(&self) -> Self {
+        let mut points = self.0.clone();
+        points.sort_by(|a, b| a.cmp_lexicographic(b));
+        points.dedup();
+        MultiPoint(points)
+    }
+}
+
+impl Bounded for MultiPoint {
+    /// Return the smallest `Envelope` covering every `Point` of this `MultiPoint`.
+    ///
+    /// An empty `MultiPoint` has an inverted `Envelope`, with its minimum corner at positive
+    /// infinity and its maximum corner at negative infinity. It intersects nothing, and taking
+    /// its `union` with another `Envelope` leaves that `Envelope` unchanged.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::envelope::{Bounded, Envelope};
+    /// use auto_gis_with_rust::point::MultiPoint;
+    ///
+    /// let multi_point = MultiPoint::new(vec![[1, 2], [3, -1], [0, 0]]);
+    ///
+    /// assert_eq!(multi_point.bounds(), Envelope::new([[0, -1], [3, 2]]));
+    ///
+    /// let empty = MultiPoint::new(Vec::<[f64; 2]>::new()).bounds();
+    ///
+    /// assert_eq!(empty.min_x(), f64::INFINITY);
+    /// assert_eq!(empty.max_y(), f64::NEG_INFINITY);
+    /// assert_eq!(empty.union(&multi_point.bounds()), multi_point.bounds());
+    /// ```
+    fn bounds(&self) -> Envelope {
+        let mut min = [f64::INFINITY; 2];
+        let mut max = [f64::NEG_INFINITY; 2];
+        for point in self.iter() {
+            min = [min[0].min(point.x()), min[1].min(point.y())];
+            max = [max[0].max(point.x()), max[1].max(point.y())];
+        }
+        Envelope::from_min_max(min, max)
+    }
+}
+
+/// `MultiPoint`s are equal if their `Point`s are equal and in the same order, so they can be used
+/// in a `HashSet` or as `HashMap` keys. Use `set_eq` to ignore the order of the `Point`s.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use auto_gis_with_rust::point::MultiPoint;
+///
+/// let multi_point = MultiPoint::new(vec![[0, 0], [1, 1]]);
+/// let mut multi_points = HashSet::new();
+/// multi_points.insert(multi_point.clone());
+/// multi_points.insert(multi_point);
+/// multi_points.insert(MultiPoint::new(vec![[1, 1], [0, 0]]));
+///
+/// assert_eq!(multi_points.len(), 2);
+/// ```
+impl Eq for MultiPoint {}
+
+impl Hash for MultiPoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
     }
 }
@@ -0,0 +1,202 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use num_traits::{self, NumCast};
+
+use crate::envelope::{Bounded, Envelope};
+use crate::helpers;
+use crate::point::Point;
+
+/// A triangle, stored as its three vertices in counter-clockwise order.
+#[derive(Debug, Clone)]
+pub struct Triangle([[f64; 2]; 3]);
+
+impl Triangle {
+    /// Construct a new `Triangle` from three vertices.
+    ///
+    /// The vertices are reordered to run counter-clockwise if they don't already.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::triangle::Triangle;
+    ///
+    /// let triangle_1 = Triangle::new([[0., 0.], [1., 0.], [0., 1.]]);
+    /// let triangle_2 = Triangle::new([[0, 0], [0, 1], [1, 0]]);
+    ///
+    /// assert_eq!(triangle_1, triangle_2);
+    /// ```
+    pub fn new<T: NumCast>(vertices: [[T; 2]; 3]) -> Self {
+        let [a, b, c]: [[f64; 2]; 3] = vertices.map(|vertex| {
+            vertex.map(|coordinate| -> f64 { num_traits::cast(coordinate).unwrap() })
+        });
+        if helpers::cross(a, b, c) < 0f64 {
+            Triangle([a, c, b])
+        } else {
+            Triangle([a, b, c])
+        }
+    }
+
+    pub fn vertices(&self) -> [Point; 3] {
+        self.0.map(|vertex| Point::new(vertex[0], vertex[1]))
+    }
+
+    /// Return the area of this `Triangle`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use auto_gis_with_rust::triangle::Triangle;
+    ///
+    /// assert_eq!(Triangle::new([[0, 0], [2, 0], [0, 3]]).area(), 3.);
+    /// ```
+    pub fn area(&self) -> f64 {
+        let [a, b, c] = self.0;
+        helpers::cross(a, b, c) / 2f64
+    }
+}
+
+impl Bounded for Triangle {
+    fn bounds(&self) -> Envelope {
+        let [a, b, c] = self.0;
+        Envelope::new([a, b]).union(&Envelope::new([c, c]))
+    }
+}
+
+/// `Triangle`s are equal if their vertices are equal and in the same order, so they can be used
+/// in a `HashSet` or as `HashMap` keys. As for `Point`s, every `NaN` is equal to every other `NaN`.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use auto_gis_with_rust::triangle::Triangle;
+///
+/// let triangle = Triangle::new([[0, 0], [1, 0], [0, 1]]);
+/// let triangles: HashSet<Triangle> =
+///     vec![triangle.clone(), triangle, Triangle::new([[0, 0], [0, 1], [1, 0]])].into_iter().collect();
+///
+/// assert_eq!(triangles.len(), 1);
+/// ```
+impl Eq for Triangle {}
+
+impl Hash for Triangle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for vertex in &self.0 {
+            helpers::hash_coordinate(vertex, state);
+        }
+    }
+}
+
+impl PartialEq for Triangle {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Triangle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(helpers::cmp_canonical(&self.0, &other.0))
+    }
+}
+
+/// Stands in for the vertex at infinity shared by the ghost triangles outside the convex hull.
+const GHOST: usize = usize::MAX;
+
+/// Return `true` if `d` is strictly inside the circumcircle of the counter-clockwise triangle
+/// `a`, `b`, `c`.
+fn in_circumcircle(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> bool {
+    let [adx, ady] = [a[0] - d[0], a[1] - d[1]];
+    let [bdx, bdy] = [b[0] - d[0], b[1] - d[1]];
+    let [cdx, cdy] = [c[0] - d[0], c[1] - d[1]];
+    let determinant = (adx * adx + ady * ady) * (bdx * cdy - cdx * bdy)
+        - (bdx * bdx + bdy * bdy) * (adx * cdy - cdx * ady)
+        + (cdx * cdx + cdy * cdy) * (adx * bdy - bdx * ady);
+    determinant > 0f64
+}
+
+/// Return `true` if `point` is inside the circumcircle of `triangle`.
+///
+/// The circumcircle of a ghost triangle `[a, b, GHOST]` is the limit of those through `a`, `b` and
+/// a vertex moving off to infinity: the open half-plane to the left of `a` to `b`, along with the
+/// open segment between them.
+fn in_conflict(vertices: &[[f64; 2]], [a, b, c]: [usize; 3], point: [f64; 2]) -> bool {
+    let [a, b] = [vertices[a], vertices[b]];
+    if c != GHOST {
+        return in_circumcircle(a, b, vertices[c], point);
+    }
+    let turn = helpers::cross(a, b, point);
+    turn > 0f64
+        || turn == 0f64
+            && helpers::dot(
+                [a[0] - point[0], a[1] - point[1]],
+                [b[0] - point[0], b[1] - point[1]],
+            ) < 0f64
+}
+
+/// Compute the Delaunay triangulation of `coordinates` with the Bowyer-Watson algorithm.
+///
+/// The triangles exactly cover the convex hull of `coordinates`: rather than a finite
+/// super-triangle, the space outside the hull is covered by ghost triangles sharing a vertex at
+/// infinity, which are removed at the end. Duplicate coordinates are used once. Collinear
+/// coordinates produce no triangles, and where four or more coordinates lie on a circle, which of
+/// the equally valid triangulations is returned depends on their order.
+pub(crate) fn delaunay(coordinates: &[[f64; 2]]) -> Vec<Triangle> {
+    let mut vertices: Vec<[f64; 2]> = Vec::with_capacity(coordinates.len());
+    for coordinate in coordinates {
+        if !vertices.contains(coordinate) {
+            vertices.push(*coordinate);
+        }
+    }
+    if vertices.len() < 3 {
+        return Vec::new();
+    }
+
+    // Start from the first two vertices and the first one not collinear with them.
+    let Some(third) = (2..vertices.len())
+        .find(|&index| helpers::cross(vertices[0], vertices[1], vertices[index]) != 0f64)
+    else {
+        return Vec::new();
+    };
+    let [first, second] = if helpers::cross(vertices[0], vertices[1], vertices[third]) > 0f64 {
+        [0, 1]
+    } else {
+        [1, 0]
+    };
+    let mut triangles: Vec<[usize; 3]> = vec![
+        [first, second, third],
+        [second, first, GHOST],
+        [third, second, GHOST],
+        [first, third, GHOST],
+    ];
+
+    for index in (2..vertices.len()).filter(|&index| index != third) {
+        let point = vertices[index];
+        let (bad, good): (Vec<[usize; 3]>, Vec<[usize; 3]>) = triangles
+            .into_iter()
+            .partition(|&triangle| in_conflict(&vertices, triangle, point));
+
+        // The edges of the hole left by the bad triangles are those only one of them has.
+        let edges: Vec<[usize; 2]> = bad
+            .iter()
+            .flat_map(|&[a, b, c]| [[a, b], [b, c], [c, a]])
+            .collect();
+        triangles = good;
+        for &[a, b] in &edges {
+            if !edges.contains(&[b, a]) {
+                // Rotate ghost triangles so that the vertex at infinity comes last.
+                triangles.push(match [a, b] {
+                    [GHOST, b] => [b, index, GHOST],
+                    [a, GHOST] => [index, a, GHOST],
+                    [a, b] => [a, b, index],
+                });
+            }
+        }
+    }
+
+    triangles
+        .into_iter()
+        .filter(|triangle| !triangle.contains(&GHOST))
+        .map(|[a, b, c]| Triangle::new([vertices[a], vertices[b], vertices[c]]))
+        .filter(|triangle| triangle.area() > 0f64)
+        .collect()
+}